@@ -6,6 +6,7 @@ import { ArrowRight, Coins, ArrowLeftRight, TrendingUp, Shield, Zap } from 'luci
 import Link from 'next/link';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useSolana } from '@/contexts/SolanaContext';
+import { PAUSE_FLAGS, isPaused } from '@/lib/anchor-setup';
 
 export default function HomePage() {
   const { connected } = useWallet();
@@ -53,8 +54,14 @@ export default function HomePage() {
               <div className="space-y-2">
                 <div className="flex justify-between">
                   <span>Status:</span>
-                  <span className={bridgeConfig.isActive ? "text-green-600" : "text-red-600"}>
-                    {bridgeConfig.isActive ? "Active" : "Inactive"}
+                  <span className={bridgeConfig.pauseFlags === 0 ? "text-green-600" : "text-red-600"}>
+                    {bridgeConfig.pauseFlags === 0
+                      ? "Active"
+                      : [
+                          isPaused(bridgeConfig, PAUSE_FLAGS.WRAP) && "Wraps paused",
+                          isPaused(bridgeConfig, PAUSE_FLAGS.UNWRAP) && "Unwraps paused",
+                          isPaused(bridgeConfig, PAUSE_FLAGS.REGISTRATION) && "Registration paused",
+                        ].filter(Boolean).join(", ")}
                   </span>
                 </div>
                 <div className="flex justify-between">
@@ -1,13 +1,27 @@
 'use client';
 
+import { useState } from 'react';
+import { PublicKey } from '@solana/web3.js';
 import { CreatePoolInterface } from '@/components/pools/CreatePoolInterface';
 import { AddLiquidityInterface } from '@/components/pools/AddLiquidityInterface';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
 
 export default function PoolsPage() {
+  const [restrictedTokenMint, setRestrictedTokenMint] = useState<PublicKey | null>(null);
   const { connected } = useWallet();
 
+  // Pools pair SOL with the bridge token of one Token2022 mint
+  const handleMintChange = (mint: string) => {
+    try {
+      setRestrictedTokenMint(new PublicKey(mint));
+    } catch (error) {
+      setRestrictedTokenMint(null);
+    }
+  };
+
   if (!connected) {
     return (
       <div className="text-center space-y-6">
@@ -29,9 +43,18 @@ export default function PoolsPage() {
         </p>
       </div>
 
+      <div className="max-w-md mx-auto space-y-2">
+        <Label htmlFor="pool-mint">Token2022 Mint Address</Label>
+        <Input
+          id="pool-mint"
+          placeholder="Enter Token2022 mint address..."
+          onChange={(e) => handleMintChange(e.target.value)}
+        />
+      </div>
+
       <div className="grid md:grid-cols-2 gap-6">
-        <CreatePoolInterface />
-        <AddLiquidityInterface />
+        <CreatePoolInterface restrictedTokenMint={restrictedTokenMint} />
+        <AddLiquidityInterface restrictedTokenMint={restrictedTokenMint} />
       </div>
 
       <div className="bg-muted p-4 rounded-lg">
@@ -1,13 +1,26 @@
 'use client';
 
+import { useState } from 'react';
+import { PublicKey } from '@solana/web3.js';
 import { SwapInterface } from '@/components/trading/SwapInterface';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
-import { useSolana } from '@/contexts/SolanaContext';
+import { getBridgeTokenMintPDA } from '@/lib/program-utils';
 
 export default function TradePage() {
+  const [restrictedTokenMint, setRestrictedTokenMint] = useState<PublicKey | null>(null);
   const { connected } = useWallet();
-  const { bridgeConfig } = useSolana();
+
+  // Bridge tokens are per Token2022 mint, so trading starts from one
+  const handleMintChange = (mint: string) => {
+    try {
+      setRestrictedTokenMint(new PublicKey(mint));
+    } catch (error) {
+      setRestrictedTokenMint(null);
+    }
+  };
 
   if (!connected) {
     return (
@@ -30,7 +43,16 @@ export default function TradePage() {
         </p>
       </div>
 
-      <SwapInterface />
+      <div className="space-y-2">
+        <Label htmlFor="trade-mint">Token2022 Mint Address</Label>
+        <Input
+          id="trade-mint"
+          placeholder="Enter Token2022 mint address..."
+          onChange={(e) => handleMintChange(e.target.value)}
+        />
+      </div>
+
+      <SwapInterface restrictedTokenMint={restrictedTokenMint} />
 
       <div className="bg-muted p-4 rounded-lg">
         <h3 className="font-semibold mb-2">About Bridge Token Trading</h3>
@@ -42,11 +64,11 @@ export default function TradePage() {
         </div>
       </div>
 
-      {bridgeConfig && (
+      {restrictedTokenMint && (
         <div className="p-3 bg-green-50 dark:bg-green-900/20 rounded-md">
           <div className="text-sm font-medium">Bridge Token Address:</div>
           <div className="text-sm font-mono break-all">
-            {bridgeConfig.bridgeTokenMint.toString()}
+            {getBridgeTokenMintPDA(restrictedTokenMint)[0].toString()}
           </div>
         </div>
       )}
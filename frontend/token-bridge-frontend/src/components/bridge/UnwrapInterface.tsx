@@ -10,6 +10,8 @@ import { Label } from '@/components/ui/label';
 import { useBridge } from '@/hooks/useBridge';
 import { useTokenBalance } from '@/hooks/useTokenBalance';
 import { useSolana } from '@/contexts/SolanaContext';
+import { PAUSE_FLAGS, isPaused } from '@/lib/anchor-setup';
+import { getBridgeTokenMintPDA, isValidPublicKey } from '@/lib/program-utils';
 import { ArrowUp, Loader2, AlertTriangle, Info } from 'lucide-react';
 
 interface UnwrapInterfaceProps {
@@ -18,20 +20,28 @@ interface UnwrapInterfaceProps {
 
 export function UnwrapInterface({ restrictedTokenMint }: UnwrapInterfaceProps) {
   const [amount, setAmount] = useState('');
+  const [recipientInput, setRecipientInput] = useState('');
   const { unwrapTokens, isLoading } = useBridge();
   const { bridgeConfig } = useSolana();
   
+  // Each restricted mint has its own bridge token mint
+  const bridgeTokenMint = restrictedTokenMint ? getBridgeTokenMintPDA(restrictedTokenMint)[0] : null;
+  const unwrapPaused = bridgeConfig ? isPaused(bridgeConfig, PAUSE_FLAGS.UNWRAP) : false;
+  const recipientInvalid = recipientInput !== '' && !isValidPublicKey(recipientInput);
+  
   const { 
     balance: bridgeBalance, 
     decimals: bridgeDecimals,
     isLoading: balanceLoading 
-  } = useTokenBalance(bridgeConfig?.bridgeTokenMint || null, false);
+  } = useTokenBalance(bridgeTokenMint, false);
 
   const handleUnwrap = async () => {
     if (!restrictedTokenMint || !amount) return;
     
     try {
-      await unwrapTokens(restrictedTokenMint, parseFloat(amount), bridgeDecimals);
+      // Unwraps to the connected wallet unless a recipient is given
+      const recipient = recipientInput ? new PublicKey(recipientInput) : undefined;
+      await unwrapTokens(restrictedTokenMint, parseFloat(amount), bridgeDecimals, recipient);
       setAmount('');
     } catch (error) {
       console.error('Unwrap failed:', error);
@@ -84,11 +94,21 @@ export function UnwrapInterface({ restrictedTokenMint }: UnwrapInterfaceProps) {
               `${bridgeBalance.toFixed(4)} tokens`
             )}
           </div>
-          <div className="text-xs text-muted-foreground mt-1">
-            Bridge Token Mint: {bridgeConfig.bridgeTokenMint.toBase58().slice(0, 8)}...
-          </div>
+          {bridgeTokenMint && (
+            <div className="text-xs text-muted-foreground mt-1">
+              Bridge Token Mint: {bridgeTokenMint.toBase58().slice(0, 8)}...
+            </div>
+          )}
         </div>
 
+        {/* Pause Warning */}
+        {unwrapPaused && (
+          <div className="text-sm text-red-600 flex items-center gap-1">
+            <AlertTriangle className="h-3 w-3" />
+            Unwraps are currently paused
+          </div>
+        )}
+
         {/* Amount Input */}
         <div className="space-y-2">
           <Label htmlFor="unwrap-amount">Amount to Unwrap</Label>
@@ -130,6 +150,23 @@ export function UnwrapInterface({ restrictedTokenMint }: UnwrapInterfaceProps) {
           )}
         </div>
 
+        {/* Optional Recipient */}
+        <div className="space-y-2">
+          <Label htmlFor="unwrap-recipient">Recipient (optional)</Label>
+          <Input
+            id="unwrap-recipient"
+            placeholder="Defaults to your wallet"
+            value={recipientInput}
+            onChange={(e) => setRecipientInput(e.target.value.trim())}
+          />
+          {recipientInvalid && (
+            <div className="text-sm text-red-600 flex items-center gap-1">
+              <AlertTriangle className="h-3 w-3" />
+              Invalid recipient address
+            </div>
+          )}
+        </div>
+
         {/* Transfer Hook Warning */}
         {restrictedTokenMint && (
           <div className="p-3 bg-blue-50 dark:bg-blue-900/20 rounded-md border border-blue-200 dark:border-blue-800">
@@ -141,7 +178,7 @@ export function UnwrapInterface({ restrictedTokenMint }: UnwrapInterfaceProps) {
                 </div>
                 <div className="text-blue-700 dark:text-blue-300 mt-1">
                   Unwrapping will validate any Transfer Hook restrictions (KYC, whitelist, transfer limits, etc.) 
-                  for the original Token2022. Make sure you meet all requirements.
+                  for the original Token2022. The recipient must meet all requirements.
                 </div>
               </div>
             </div>
@@ -182,7 +219,7 @@ export function UnwrapInterface({ restrictedTokenMint }: UnwrapInterfaceProps) {
                 <span className="font-mono">{parseFloat(amount).toFixed(4)}</span>
               </div>
               <div className="text-xs text-green-600 dark:text-green-400 mt-2">
-                1:1 exchange rate guaranteed
+                1:1 before the unwrap fee and any Token2022 transfer fee
               </div>
             </div>
           </div>
@@ -197,6 +234,8 @@ export function UnwrapInterface({ restrictedTokenMint }: UnwrapInterfaceProps) {
             !amount || 
             parseFloat(amount) <= 0 || 
             parseFloat(amount) > bridgeBalance ||
+            recipientInvalid ||
+            unwrapPaused ||
             isLoading ||
             balanceLoading
           }
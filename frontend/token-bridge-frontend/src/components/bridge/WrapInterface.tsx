@@ -8,6 +8,8 @@ import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { useBridge } from '@/hooks/useBridge';
 import { useTokenBalance } from '@/hooks/useTokenBalance';
+import { useSolana } from '@/contexts/SolanaContext';
+import { PAUSE_FLAGS, isPaused } from '@/lib/anchor-setup';
 import { ArrowDown, Loader2 } from 'lucide-react';
 
 interface WrapInterfaceProps {
@@ -19,6 +21,8 @@ export function WrapInterface({ restrictedTokenMint, onMintChange }: WrapInterfa
   const [amount, setAmount] = useState('');
   const [mintInput, setMintInput] = useState('');
   const { wrapTokens, isLoading } = useBridge();
+  const { bridgeConfig } = useSolana();
+  const wrapPaused = bridgeConfig ? isPaused(bridgeConfig, PAUSE_FLAGS.WRAP) : false;
 
   const { 
     balance: restrictedBalance, 
@@ -67,6 +71,10 @@ export function WrapInterface({ restrictedTokenMint, onMintChange }: WrapInterfa
           />
         </div>
 
+        {wrapPaused && (
+          <div className="text-sm text-red-600">Wraps are currently paused</div>
+        )}
+
         {restrictedTokenMint && (
           <>
             <div className="p-3 bg-muted rounded-md">
@@ -112,6 +120,7 @@ export function WrapInterface({ restrictedTokenMint, onMintChange }: WrapInterfa
             !amount || 
             parseFloat(amount) <= 0 || 
             parseFloat(amount) > restrictedBalance ||
+            wrapPaused ||
             isLoading
           }
           className="w-full"
@@ -9,12 +9,17 @@ import { Label } from '@/components/ui/label';
 import { useOrca } from '@/hooks/useOrca';
 import { useTokenBalance } from '@/hooks/useTokenBalance';
 import { useSolana } from '@/contexts/SolanaContext';
+import { getBridgeTokenMintPDA } from '@/lib/program-utils';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { Plus, Loader2 } from 'lucide-react';
 
 const SOL_MINT = 'So11111111111111111111111111111111111111112';
 
-export function AddLiquidityInterface() {
+interface AddLiquidityInterfaceProps {
+  restrictedTokenMint: PublicKey | null;
+}
+
+export function AddLiquidityInterface({ restrictedTokenMint }: AddLiquidityInterfaceProps) {
   const [poolAddress, setPoolAddress] = useState('');
   const [solAmount, setSolAmount] = useState('');
   const [bridgeAmount, setBridgeAmount] = useState('');
@@ -24,10 +29,10 @@ export function AddLiquidityInterface() {
   const { publicKey } = useWallet();
   
   const { balance: solBalance } = useTokenBalance(new PublicKey(SOL_MINT), false);
-  const { balance: bridgeBalance } = useTokenBalance(
-    bridgeConfig?.bridgeTokenMint || null,
-    false
-  );
+  
+  // Bridge token of the selected Token2022 mint
+  const bridgeTokenMint = restrictedTokenMint ? getBridgeTokenMintPDA(restrictedTokenMint)[0] : null;
+  const { balance: bridgeBalance } = useTokenBalance(bridgeTokenMint, false);
 
   const handleAddLiquidity = async () => {
     if (!poolAddress || !solAmount || !bridgeAmount) return;
@@ -9,12 +9,17 @@ import { Label } from '@/components/ui/label';
 import { useOrca } from '@/hooks/useOrca';
 import { useTokenBalance } from '@/hooks/useTokenBalance';
 import { useSolana } from '@/contexts/SolanaContext';
+import { getBridgeTokenMintPDA } from '@/lib/program-utils';
 import { Droplets, Loader2, Info } from 'lucide-react';
 
 // SOL mint address
 const SOL_MINT = 'So11111111111111111111111111111111111111112';
 
-export function CreatePoolInterface() {
+interface CreatePoolInterfaceProps {
+  restrictedTokenMint: PublicKey | null;
+}
+
+export function CreatePoolInterface({ restrictedTokenMint }: CreatePoolInterfaceProps) {
   const [initialPrice, setInitialPrice] = useState('');
   const [poolCreated, setPoolCreated] = useState<{
     poolAddress: PublicKey;
@@ -24,17 +29,17 @@ export function CreatePoolInterface() {
   const { createSplashPool, isLoading } = useOrca();
   const { bridgeConfig } = useSolana();
   
-  const { balance: bridgeTokenBalance } = useTokenBalance(
-    bridgeConfig?.bridgeTokenMint || null,
-    false
-  );
+  // Bridge token of the selected Token2022 mint
+  const bridgeTokenMint = restrictedTokenMint ? getBridgeTokenMintPDA(restrictedTokenMint)[0] : null;
+  
+  const { balance: bridgeTokenBalance } = useTokenBalance(bridgeTokenMint, false);
 
   const handleCreatePool = async () => {
-    if (!bridgeConfig || !initialPrice) return;
+    if (!bridgeConfig || !bridgeTokenMint || !initialPrice) return;
     
     try {
       const tokenA = new PublicKey(SOL_MINT);
-      const tokenB = bridgeConfig.bridgeTokenMint;
+      const tokenB = bridgeTokenMint;
       
       const result = await createSplashPool(
         tokenA,
@@ -140,7 +145,7 @@ export function CreatePoolInterface() {
           <div className="space-y-2">
             <Label>Token B</Label>
             <Input 
-              value="Bridge Token" 
+              value={bridgeTokenMint ? `Bridge Token (${bridgeTokenMint.toBase58().slice(0, 8)}...)` : 'Select a Token2022 mint'} 
               readOnly 
               className="bg-muted" 
             />
@@ -177,6 +182,7 @@ export function CreatePoolInterface() {
         <Button
           onClick={handleCreatePool}
           disabled={
+            !bridgeTokenMint ||
             !initialPrice || 
             parseFloat(initialPrice) <= 0 ||
             isLoading
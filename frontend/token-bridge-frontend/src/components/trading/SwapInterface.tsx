@@ -8,7 +8,7 @@ import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { useJupiter } from '@/hooks/useJupiter';
 import { useTokenBalance } from '@/hooks/useTokenBalance';
-import { useSolana } from '@/contexts/SolanaContext';
+import { getBridgeTokenMintPDA } from '@/lib/program-utils';
 import { TrendingUp, Loader2, ArrowDownUp } from 'lucide-react';
 
 // Common token addresses on devnet
@@ -17,7 +17,11 @@ const COMMON_TOKENS = {
   USDC: 'BRjpCHtyQLNCo8gqRUr8jtdAj5AjPYQaoqbvcZiHok1k', // devUSDC
 };
 
-export function SwapInterface() {
+interface SwapInterfaceProps {
+  restrictedTokenMint: PublicKey | null;
+}
+
+export function SwapInterface({ restrictedTokenMint }: SwapInterfaceProps) {
   const [inputMint, setInputMint] = useState(COMMON_TOKENS.SOL);
   const [outputMint, setOutputMint] = useState('');
   const [inputAmount, setInputAmount] = useState('');
@@ -25,7 +29,9 @@ export function SwapInterface() {
   const [quoteLoading, setQuoteLoading] = useState(false);
   
   const { getQuote, executeSwap, isLoading } = useJupiter();
-  const { bridgeConfig } = useSolana();
+  
+  // Bridge token of the selected Token2022 mint
+  const bridgeTokenMint = restrictedTokenMint ? getBridgeTokenMintPDA(restrictedTokenMint)[0] : null;
   
   const { balance: inputBalance } = useTokenBalance(
     inputMint ? new PublicKey(inputMint) : null,
@@ -34,10 +40,10 @@ export function SwapInterface() {
 
   // Set bridge token as default output if available
   useEffect(() => {
-    if (bridgeConfig && !outputMint) {
-      setOutputMint(bridgeConfig.bridgeTokenMint.toString());
+    if (bridgeTokenMint && !outputMint) {
+      setOutputMint(bridgeTokenMint.toString());
     }
-  }, [bridgeConfig, outputMint]);
+  }, [bridgeTokenMint, outputMint]);
 
   // Get quote when inputs change
   useEffect(() => {
@@ -102,8 +108,8 @@ export function SwapInterface() {
             >
               <option value={COMMON_TOKENS.SOL}>SOL</option>
               <option value={COMMON_TOKENS.USDC}>USDC (Dev)</option>
-              {bridgeConfig && (
-                <option value={bridgeConfig.bridgeTokenMint.toString()}>
+              {bridgeTokenMint && (
+                <option value={bridgeTokenMint.toString()}>
                   Bridge Token
                 </option>
               )}
@@ -156,8 +162,8 @@ export function SwapInterface() {
               <option value="">Select token...</option>
               <option value={COMMON_TOKENS.SOL}>SOL</option>
               <option value={COMMON_TOKENS.USDC}>USDC (Dev)</option>
-              {bridgeConfig && (
-                <option value={bridgeConfig.bridgeTokenMint.toString()}>
+              {bridgeTokenMint && (
+                <option value={bridgeTokenMint.toString()}>
                   Bridge Token
                 </option>
               )}
@@ -4,7 +4,7 @@ import React, { createContext, useContext, useEffect, useState } from 'react';
 import { Connection, PublicKey } from '@solana/web3.js';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
 import { Program } from '@coral-xyz/anchor';
-import { getProgram } from '@/lib/anchor-setup';
+import { getProgram, convertBridgeConfig } from '@/lib/anchor-setup';
 import type { BridgeConfig } from '@/lib/anchor-setup';
 import { getBridgeConfigPDA } from '@/lib/program-utils';
 
@@ -55,14 +55,7 @@ export function SolanaProvider({ children }: { children: React.ReactNode }) {
       const accountData = await (program.account as any).bridgeConfig.fetch(bridgeConfigPDA);
       
       // Transform the raw account data to our TypeScript interface
-      const config: BridgeConfig = {
-        authority: accountData.authority,
-        bump: accountData.bump,
-        bridgeTokenMint: accountData.bridgeTokenMint,
-        approvedHookPrograms: accountData.approvedHookPrograms,
-        totalLockedAmount: accountData.totalLockedAmount,
-        isActive: accountData.isActive,
-      };
+      const config: BridgeConfig = convertBridgeConfig(accountData);
       
       setBridgeConfig(config);
     } catch (error) {
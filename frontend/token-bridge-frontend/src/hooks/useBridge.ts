@@ -2,15 +2,25 @@
 
 import { useState } from 'react';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
-import { PublicKey, SystemProgram } from '@solana/web3.js';
-import { 
-  TOKEN_PROGRAM_ID, 
-  TOKEN_2022_PROGRAM_ID, 
+import { AccountMeta, PublicKey } from '@solana/web3.js';
+import {
+  TOKEN_2022_PROGRAM_ID,
+  addExtraAccountMetasForExecute,
+  createTransferCheckedInstruction,
+  getMint,
+  getTransferHook,
 } from '@solana/spl-token';
 import * as anchor from '@coral-xyz/anchor';
 import { useSolana } from '@/contexts/SolanaContext';
-import { 
-  getUserTokenAccount 
+import { PAUSE_FLAGS, isPaused } from '@/lib/anchor-setup';
+import {
+  getHookMetadataPDA,
+  getTokenVaultPDA,
+  getUnwrapTokensAccounts,
+  getUserRestrictedTokenAccount,
+  getVaultTokenAccount,
+  getWhitelistPDA,
+  getWrapTokensAccounts,
 } from '@/lib/program-utils';
 import { toast } from 'sonner';
 
@@ -20,6 +30,88 @@ export function useBridge() {
   const { program, bridgeConfig, refreshBridgeConfig } = useSolana();
   const [isLoading, setIsLoading] = useState(false);
 
+  // Loads the vault of `restrictedTokenMint`, failing early where the
+  // program would reject the transaction anyway.
+  const fetchVault = async (restrictedTokenMint: PublicKey, flag: number) => {
+    const [tokenVault] = getTokenVaultPDA(restrictedTokenMint);
+    const vault = await (program!.account as any).tokenVault.fetchNullable(tokenVault);
+
+    if (!vault) {
+      throw new Error('No vault is registered for this mint');
+    }
+    if (isPaused(bridgeConfig!, flag) || isPaused(vault, flag)) {
+      throw new Error(flag === PAUSE_FLAGS.WRAP ? 'Wraps are paused' : 'Unwraps are paused');
+    }
+    if (flag === PAUSE_FLAGS.WRAP && !vault.isEnabled) {
+      throw new Error('Wraps are disabled for this vault');
+    }
+
+    return { tokenVault, vault };
+  };
+
+  // Whitelist and KYC hooks are checked by the bridge itself and need the
+  // matching account for `party`; other hook types need neither.
+  const getHookAccounts = async (
+    restrictedTokenMint: PublicKey,
+    tokenVault: PublicKey,
+    party: PublicKey
+  ) => {
+    const [hookMetadataPDA] = getHookMetadataPDA(tokenVault);
+    const hookMetadata = await (program!.account as any).hookMetadata.fetch(hookMetadataPDA);
+
+    if (hookMetadata.hookType.whitelist) {
+      return { whitelist: getWhitelistPDA(restrictedTokenMint)[0] };
+    }
+    if (hookMetadata.hookType.kyc) {
+      const [kycAttestation] = PublicKey.findProgramAddressSync(
+        [Buffer.from('kyc'), restrictedTokenMint.toBuffer(), party.toBuffer()],
+        hookMetadata.hookProgramId
+      );
+      return { kycAttestation };
+    }
+    return {};
+  };
+
+  // Extra accounts the mint's transfer hook needs for the vault transfer,
+  // passed to the bridge as remaining accounts.
+  const getTransferHookAccounts = async (
+    restrictedTokenMint: PublicKey,
+    source: PublicKey,
+    destination: PublicKey,
+    owner: PublicKey,
+    amount: bigint
+  ): Promise<AccountMeta[]> => {
+    const mint = await getMint(connection, restrictedTokenMint, 'confirmed', TOKEN_2022_PROGRAM_ID);
+    if (!getTransferHook(mint)) {
+      return [];
+    }
+
+    const transfer = createTransferCheckedInstruction(
+      source,
+      restrictedTokenMint,
+      destination,
+      owner,
+      amount,
+      mint.decimals,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    );
+    await addExtraAccountMetasForExecute(
+      connection,
+      transfer,
+      getTransferHook(mint)!.programId,
+      source,
+      restrictedTokenMint,
+      destination,
+      owner,
+      amount,
+      'confirmed'
+    );
+
+    // Everything after source, mint, destination and owner
+    return transfer.keys.slice(4);
+  };
+
   const wrapTokens = async (
     restrictedTokenMint: PublicKey,
     amount: number,
@@ -32,41 +124,34 @@ export function useBridge() {
     setIsLoading(true);
     try {
       const amountBN = new anchor.BN(amount * Math.pow(10, decimals));
-      
-      // Get only the required accounts - let Anchor handle the rest
-      const userRestrictedTokenAccount = getUserTokenAccount(
-        restrictedTokenMint, 
-        publicKey, 
-        true // Token2022
+      const { tokenVault } = await fetchVault(restrictedTokenMint, PAUSE_FLAGS.WRAP);
+
+      const accounts = getWrapTokensAccounts(
+        publicKey,
+        restrictedTokenMint,
+        await getHookAccounts(restrictedTokenMint, tokenVault, publicKey)
       );
-      
-      const userBridgeTokenAccount = getUserTokenAccount(
-        bridgeConfig.bridgeTokenMint, 
-        publicKey, 
-        false // Standard SPL
+      const remainingAccounts = await getTransferHookAccounts(
+        restrictedTokenMint,
+        accounts.userRestrictedTokenAccount,
+        accounts.vaultTokenAccount,
+        publicKey,
+        BigInt(amountBN.toString())
       );
 
       // Build the transaction with proper account resolution
       const transaction = await (program.methods as any)
         .wrapTokens(amountBN)
-        .accounts({
-          user: publicKey,
-          restrictedTokenMint,
-          userRestrictedTokenAccount,
-          bridgeTokenMint: bridgeConfig.bridgeTokenMint,
-          userBridgeTokenAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
+        .accountsPartial(accounts)
+        .remainingAccounts(remainingAccounts)
         .transaction();
 
       const signature = await sendTransaction(transaction, connection);
       await connection.confirmTransaction(signature, 'confirmed');
-      
+
       toast.success(`Successfully wrapped ${amount} tokens!`);
       await refreshBridgeConfig();
-      
+
       return signature;
     } catch (error: any) {
       console.error('Wrap tokens error:', error);
@@ -77,10 +162,12 @@ export function useBridge() {
     }
   };
 
+  // Pays out to `recipient`, the connected wallet by default
   const unwrapTokens = async (
     restrictedTokenMint: PublicKey,
     amount: number,
-    decimals: number = 9
+    decimals: number = 9,
+    recipient?: PublicKey
   ): Promise<string> => {
     if (!program || !publicKey || !bridgeConfig) {
       throw new Error('Wallet not connected or program not initialized');
@@ -89,41 +176,37 @@ export function useBridge() {
     setIsLoading(true);
     try {
       const amountBN = new anchor.BN(amount * Math.pow(10, decimals));
-      
-      // Get only the required accounts - let Anchor handle the rest
-      const userRestrictedTokenAccount = getUserTokenAccount(
-        restrictedTokenMint, 
-        publicKey, 
-        true // Token2022
+      const payee = recipient ?? publicKey;
+      const { tokenVault } = await fetchVault(restrictedTokenMint, PAUSE_FLAGS.UNWRAP);
+
+      const accounts = getUnwrapTokensAccounts(
+        publicKey,
+        restrictedTokenMint,
+        payee,
+        await getHookAccounts(restrictedTokenMint, tokenVault, payee)
       );
-      
-      const userBridgeTokenAccount = getUserTokenAccount(
-        bridgeConfig.bridgeTokenMint, 
-        publicKey, 
-        false // Standard SPL
+      // The vault itself signs the transfer out
+      const remainingAccounts = await getTransferHookAccounts(
+        restrictedTokenMint,
+        getVaultTokenAccount(restrictedTokenMint, tokenVault),
+        getUserRestrictedTokenAccount(restrictedTokenMint, payee),
+        tokenVault,
+        BigInt(amountBN.toString())
       );
 
       // Build the transaction with proper account resolution
-      const transaction = await program.methods
+      const transaction = await (program.methods as any)
         .unwrapTokens(amountBN)
-        .accounts({
-          user: publicKey,
-          restrictedTokenMint,
-          userRestrictedTokenAccount,
-          bridgeTokenMint: bridgeConfig.bridgeTokenMint,
-          userBridgeTokenAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
+        .accountsPartial(accounts)
+        .remainingAccounts(remainingAccounts)
         .transaction();
 
       const signature = await sendTransaction(transaction, connection);
       await connection.confirmTransaction(signature, 'confirmed');
-      
+
       toast.success(`Successfully unwrapped ${amount} tokens!`);
       await refreshBridgeConfig();
-      
+
       return signature;
     } catch (error: any) {
       console.error('Unwrap tokens error:', error);
@@ -139,4 +222,4 @@ export function useBridge() {
     unwrapTokens,
     isLoading
   };
-}
\ No newline at end of file
+}
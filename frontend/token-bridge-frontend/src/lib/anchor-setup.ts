@@ -14,14 +14,12 @@ export const IDL = {
   },
   "instructions": [
     {
-      "name": "addApprovedHookProgram",
-      "discriminator": [31, 196, 45, 58, 225, 80, 46, 77],
+      "name": "acceptAuthority",
+      "discriminator": [107, 86, 198, 91, 33, 12, 107, 160],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": ["bridgeConfig"]
+          "name": "pendingAuthority",
+          "signer": true
         },
         {
           "name": "bridgeConfig",
@@ -34,27 +32,35 @@ export const IDL = {
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "hookProgramId",
-          "type": "pubkey"
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "addToWhitelist",
-      "discriminator": [157, 211, 52, 54, 144, 81, 5, 55],
+      "name": "acceptWhitelistAuthority",
+      "discriminator": [255, 155, 168, 151, 15, 200, 80, 86],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": ["whitelist"]
+          "name": "pendingAuthority",
+          "signer": true
         },
         {
-          "name": "mint"
+          "name": "mint",
+          "relations": ["whitelist"]
         },
         {
           "name": "whitelist",
@@ -71,58 +77,30 @@ export const IDL = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "user",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "createBridgeTokenMint",
-      "discriminator": [197, 42, 209, 137, 129, 254, 229, 172],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": ["bridgeConfig"]
         },
         {
-          "name": "bridgeConfig",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
               }
             ]
           }
         },
         {
-          "name": "bridgeTokenMint",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "initializeBridge",
-      "discriminator": [6, 173, 152, 229, 35, 112, 127, 151],
+      "name": "addApprovedHookProgram",
+      "discriminator": [31, 196, 45, 58, 225, 80, 46, 77],
       "accounts": [
         {
-          "name": "authority",
+          "name": "admin",
           "writable": true,
           "signer": true
         },
@@ -139,68 +117,29 @@ export const IDL = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeWhitelist",
-      "discriminator": [223, 228, 11, 219, 112, 174, 108, 18],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "mint"
-        },
-        {
-          "name": "whitelist",
-          "writable": true,
+          "name": "bridgeRoles",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
-              },
-              {
-                "kind": "account",
-                "path": "mint"
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "removeApprovedHookProgram",
-      "discriminator": [71, 13, 68, 145, 135, 114, 19, 77],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": ["bridgeConfig"]
-        },
-        {
-          "name": "bridgeConfig",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
               }
             ]
           }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": [
@@ -211,8 +150,8 @@ export const IDL = {
       ]
     },
     {
-      "name": "removeFromWhitelist",
-      "discriminator": [7, 144, 216, 239, 243, 236, 193, 235],
+      "name": "addToWhitelist",
+      "discriminator": [157, 211, 52, 54, 144, 81, 5, 55],
       "accounts": [
         {
           "name": "authority",
@@ -221,7 +160,8 @@ export const IDL = {
           "relations": ["whitelist"]
         },
         {
-          "name": "mint"
+          "name": "mint",
+          "relations": ["whitelist"]
         },
         {
           "name": "whitelist",
@@ -238,6 +178,20 @@ export const IDL = {
               }
             ]
           }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": [
@@ -248,18 +202,15 @@ export const IDL = {
       ]
     },
     {
-      "name": "toggleBridgeStatus",
-      "discriminator": [156, 238, 111, 48, 67, 196, 116, 155],
+      "name": "approveAdminProposal",
+      "discriminator": [69, 210, 165, 107, 217, 184, 67, 24],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": ["bridgeConfig"]
+          "name": "approver",
+          "signer": true
         },
         {
           "name": "bridgeConfig",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -268,242 +219,325 @@ export const IDL = {
               }
             ]
           }
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "unwrapTokens",
-      "discriminator": [17, 121, 3, 250, 67, 105, 232, 113],
-      "accounts": [
-        {
-          "name": "user",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "bridgeConfig",
+          "name": "adminMultisig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+                "value": [97, 100, 109, 105, 110, 95, 109, 117, 108, 116, 105, 115, 105, 103]
               }
             ]
           }
         },
         {
-          "name": "restrictedTokenMint"
-        },
-        {
-          "name": "userRestrictedTokenAccount",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "user"
+                "kind": "const",
+                "value": [97, 100, 109, 105, 110, 95, 112, 114, 111, 112, 111, 115, 97, 108]
               },
               {
                 "kind": "account",
-                "path": "token2022Program"
+                "path": "adminMultisig"
               },
               {
                 "kind": "account",
-                "path": "restrictedTokenMint"
+                "path": "proposal.index",
+                "account": "adminProposal"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
-            }
+            ]
           }
         },
         {
-          "name": "tokenVault",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
-              },
-              {
-                "kind": "account",
-                "path": "restrictedTokenMint"
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
               }
             ]
           }
         },
         {
-          "name": "vaultTokenAccount",
-          "writable": true,
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAdminProposal",
+      "discriminator": [68, 6, 145, 131, 16, 73, 182, 229],
+      "accounts": [
+        {
+          "name": "guardian",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "tokenVault"
-              },
-              {
-                "kind": "account",
-                "path": "token2022Program"
-              },
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
               {
-                "kind": "account",
-                "path": "restrictedTokenMint"
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
-            }
+            ]
           }
         },
         {
-          "name": "bridgeTokenMint",
-          "writable": true
+          "name": "adminMultisig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 100, 109, 105, 110, 95, 109, 117, 108, 116, 105, 115, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "userBridgeTokenAccount",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "user"
+                "kind": "const",
+                "value": [97, 100, 109, 105, 110, 95, 112, 114, 111, 112, 111, 115, 97, 108]
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "adminMultisig"
               },
               {
                 "kind": "account",
-                "path": "bridgeTokenMint"
+                "path": "proposal.index",
+                "account": "adminProposal"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
-            }
+            ]
           }
         },
         {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "token2022Program"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "program"
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "cancelAuthorityTransfer",
+      "discriminator": [94, 131, 125, 184, 183, 24, 125, 229],
+      "accounts": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "updateBridgeAuthority",
-      "discriminator": [126, 176, 226, 17, 50, 68, 117, 193],
+      "name": "cancelWhitelistAuthorityTransfer",
+      "discriminator": [237, 37, 10, 96, 132, 126, 150, 35],
       "accounts": [
         {
           "name": "authority",
           "writable": true,
           "signer": true,
-          "relations": ["bridgeConfig"]
+          "relations": ["whitelist"]
         },
         {
-          "name": "bridgeConfig",
+          "name": "mint",
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "whitelist",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "newAuthority",
-          "type": "pubkey"
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "whitelistTransferHook",
-      "discriminator": [124, 182, 48, 78, 57, 220, 170, 166],
+      "name": "createAdminProposal",
+      "discriminator": [225, 104, 68, 10, 114, 133, 212, 222],
       "accounts": [
         {
-          "name": "sourceToken"
-        },
-        {
-          "name": "mint"
+          "name": "proposer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "destinationToken"
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "owner"
+          "name": "adminMultisig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 100, 109, 105, 110, 95, 109, 117, 108, 116, 105, 115, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "extraAccountMetaList",
+          "name": "proposal",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [101, 120, 116, 114, 97, 45, 97, 99, 99, 111, 117, 110, 116, 45, 109, 101, 116, 97, 115]
+                "value": [97, 100, 109, 105, 110, 95, 112, 114, 111, 112, 111, 115, 97, 108]
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "adminMultisig"
+              },
+              {
+                "kind": "account",
+                "path": "admin_multisig.proposal_count",
+                "account": "adminMultisig"
               }
             ]
           }
         },
         {
-          "name": "whitelist",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
-              },
-              {
-                "kind": "account",
-                "path": "mint"
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
               }
             ]
           }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "adminAction"
+            }
+          }
         }
       ]
     },
     {
-      "name": "wrapTokens",
-      "discriminator": [244, 137, 57, 251, 232, 224, 54, 14],
+      "name": "executeAdminProposal",
+      "discriminator": [157, 50, 31, 216, 56, 72, 129, 159],
       "accounts": [
         {
-          "name": "user",
-          "writable": true,
+          "name": "executor",
           "signer": true
         },
         {
           "name": "bridgeConfig",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -514,115 +548,5345 @@ export const IDL = {
           }
         },
         {
-          "name": "restrictedTokenMint"
-        },
-        {
-          "name": "userRestrictedTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "tokenVault",
+          "name": "adminMultisig",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
-                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
-              },
-              {
-                "kind": "account",
-                "path": "restrictedTokenMint"
+                "value": [97, 100, 109, 105, 110, 95, 109, 117, 108, 116, 105, 115, 105, 103]
               }
             ]
           }
         },
         {
-          "name": "vaultTokenAccount",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "tokenVault"
+                "kind": "const",
+                "value": [97, 100, 109, 105, 110, 95, 112, 114, 111, 112, 111, 115, 97, 108]
               },
               {
                 "kind": "account",
-                "path": "token2022Program"
+                "path": "adminMultisig"
               },
               {
                 "kind": "account",
-                "path": "restrictedTokenMint"
+                "path": "proposal.index",
+                "account": "adminProposal"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
-            }
+            ]
           }
         },
         {
-          "name": "bridgeTokenMint",
-          "writable": true
+          "name": "tokenVault",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "userBridgeTokenAccount",
+          "name": "hookMetadata",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bridgeRoles",
           "writable": true,
+          "optional": true
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "user"
-              },
-              {
-                "kind": "account",
-                "path": "tokenProgram"
-              },
-              {
-                "kind": "account",
-                "path": "bridgeTokenMint"
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
-            }
+            ]
           }
         },
         {
-          "name": "tokenProgram"
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "grantRole",
+      "discriminator": [218, 234, 128, 15, 82, 33, 236, 253],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
         },
         {
-          "name": "token2022Program"
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "bridgeRoles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "member",
+          "type": "pubkey"
+        },
+        {
+          "name": "roles",
+          "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "initializeAdminMultisig",
+      "discriminator": [3, 19, 239, 200, 115, 54, 151, 60],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": ["bridgeConfig"]
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminMultisig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 100, 109, 105, 110, 95, 109, 117, 108, 116, 105, 115, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "signers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeBridge",
+      "discriminator": [6, 173, 152, 229, 35, 112, 127, 151],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeProgram",
+          "address": "Hfvd4ZLYac9wHs8fz4Yo3DCNqU1qRScMY4tu9GwQP7gw"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeWhitelist",
+      "discriminator": [223, 228, 11, 219, 112, 174, 108, 18],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "whitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "extraAccountMetaList",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 120, 116, 114, 97, 45, 97, 99, 99, 111, 117, 110, 116, 45, 109, 101, 116, 97, 115]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateBridgeConfig",
+      "discriminator": [58, 20, 134, 143, 54, 144, 190, 179],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateLegacyVault",
+      "discriminator": [38, 235, 213, 175, 164, 254, 27, 85],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateLegacyWhitelist",
+      "discriminator": [32, 88, 228, 116, 38, 245, 97, 113],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "whitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pause",
+      "discriminator": [211, 22, 221, 251, 74, 121, 193, 47],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        },
+        {
+          "name": "pauseReason",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "pauseVault",
+      "discriminator": [250, 6, 228, 57, 6, 104, 19, 210],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        },
+        {
+          "name": "pauseReason",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "discriminator": [20, 148, 236, 198, 76, 119, 99, 142],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "proposeWhitelistAuthority",
+      "discriminator": [24, 22, 254, 153, 116, 224, 75, 21],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "mint",
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "whitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "reconcileVaultSurplus",
+      "discriminator": [56, 9, 143, 247, 11, 130, 112, 108],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "bridgeTokenMint",
+          "writable": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "recipientTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "adminMultisig",
+          "optional": true
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "token2022Program"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": {
+              "name": "surplusMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemLegacyTokens",
+      "discriminator": [66, 120, 69, 164, 115, 147, 183, 86],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "holderRestrictedTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holder"
+              },
+              {
+                "kind": "account",
+                "path": "token2022Program"
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hookMetadata",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [104, 111, 111, 107, 95, 109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "legacyBridgeTokenMint",
+          "writable": true
+        },
+        {
+          "name": "holderLegacyTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "whitelist",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "kycAttestation",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "token2022Program"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "refreshBridgeTokenMetadata",
+      "discriminator": [239, 47, 148, 243, 70, 180, 72, 94],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "bridgeTokenMint"
+        },
+        {
+          "name": "bridgeTokenMetadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "bridgeTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerVault",
+      "discriminator": [121, 62, 4, 122, 93, 231, 119, 49],
+      "accounts": [
+        {
+          "name": "registrar",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              },
+              {
+                "kind": "account",
+                "path": "token2022Program"
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "bridgeTokenMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 116, 111, 107, 101, 110, 95, 109, 105, 110, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hookMetadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [104, 111, 111, 107, 95, 109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [102, 101, 101, 95, 97, 99, 99, 111, 117, 110, 116]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeTokenMetadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "bridgeTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "token2022Program"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "hookType",
+          "type": {
+            "defined": {
+              "name": "hookType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeApprovedHookProgram",
+      "discriminator": [71, 13, 68, 145, 135, 114, 19, 77],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "hookProgramId",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "removeFromWhitelist",
+      "discriminator": [7, 144, 216, 239, 243, 236, 193, 235],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "mint",
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "whitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "discriminator": [179, 232, 2, 180, 48, 227, 82, 7],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "pubkey"
+        },
+        {
+          "name": "roles",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setAdmissionOverrides",
+      "discriminator": [82, 144, 188, 93, 131, 3, 196, 252],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "admissionOverrides",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setFeeConfig",
+      "discriminator": [221, 222, 52, 206, 114, 198, 64, 91],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "defaultWrapFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "defaultUnwrapFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setPermissionlessVaultRegistration",
+      "discriminator": [79, 79, 248, 38, 225, 110, 74, 96],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTimelockConfig",
+      "discriminator": [176, 167, 10, 233, 251, 255, 15, 254],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "delay",
+          "type": "i64"
+        },
+        {
+          "name": "exemptions",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setTreasury",
+      "discriminator": [57, 97, 196, 95, 195, 206, 106, 136],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setUserQuota",
+      "discriminator": [156, 42, 166, 218, 72, 219, 36, 66],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userQuota",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [117, 115, 101, 114, 95, 113, 117, 111, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminMultisig",
+          "optional": true
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        },
+        {
+          "name": "wrapQuota",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "unwrapQuota",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setVaultEnabled",
+      "discriminator": [84, 61, 18, 78, 96, 54, 253, 193],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setVaultFees",
+      "discriminator": [179, 184, 126, 163, 45, 212, 76, 140],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "wrapFeeBps",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "unwrapFeeBps",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setVaultLimits",
+      "discriminator": [67, 44, 183, 92, 254, 37, 141, 249],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "limits",
+          "type": {
+            "defined": {
+              "name": "vaultLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setVaultRateLimits",
+      "discriminator": [100, 138, 79, 185, 97, 65, 26, 168],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "wrap",
+          "type": {
+            "defined": {
+              "name": "rateLimitConfig"
+            }
+          }
+        },
+        {
+          "name": "unwrap",
+          "type": {
+            "defined": {
+              "name": "rateLimitConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setVaultUserQuotas",
+      "discriminator": [251, 124, 222, 43, 153, 95, 236, 123],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "userQuotas",
+          "type": {
+            "defined": {
+              "name": "userQuotaConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "toggleWhitelistStatus",
+      "discriminator": [6, 102, 234, 110, 113, 75, 226, 131],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "mint",
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "whitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "discriminator": [169, 144, 4, 38, 10, 141, 188, 255],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unpauseVault",
+      "discriminator": [125, 29, 213, 213, 114, 155, 125, 63],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unwrapTokens",
+      "discriminator": [17, 121, 3, 250, 67, 105, 232, 113],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "recipient"
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "recipientTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "recipient"
+              },
+              {
+                "kind": "account",
+                "path": "token2022Program"
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hookMetadata",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [104, 111, 111, 107, 95, 109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              },
+              {
+                "kind": "account",
+                "path": "token2022Program"
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "bridgeTokenMint",
+          "writable": true
+        },
+        {
+          "name": "userBridgeTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "bridgeTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "feeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [102, 101, 101, 95, 97, 99, 99, 111, 117, 110, 116]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userQuota",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [117, 115, 101, 114, 95, 113, 117, 111, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelist",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "kycAttestation",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "token2022Program"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateHookMetadata",
+      "discriminator": [3, 148, 66, 101, 126, 39, 1, 98],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hookMetadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [104, 111, 111, 107, 95, 109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "hookType",
+          "type": {
+            "defined": {
+              "name": "hookType"
+            }
+          }
+        },
+        {
+          "name": "isActive",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "verifyReserves",
+      "discriminator": [0, 168, 188, 229, 128, 121, 149, 172],
+      "accounts": [
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount"
+        },
+        {
+          "name": "bridgeTokenMint"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "whitelistTransferHook",
+      "discriminator": [124, 182, 48, 78, 57, 220, 170, 166],
+      "accounts": [
+        {
+          "name": "sourceToken"
+        },
+        {
+          "name": "mint",
+          "relations": ["whitelist"]
+        },
+        {
+          "name": "destinationToken"
+        },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "extraAccountMetaList",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 120, 116, 114, 97, 45, 97, 99, 99, 111, 117, 110, 116, 45, 109, 101, 116, 97, 115]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFees",
+      "discriminator": [198, 212, 171, 109, 144, 215, 174, 89],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeRoles",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 114, 111, 108, 101, 115]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "token_vault.restricted_token_mint",
+                "account": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bridgeTokenMint"
+        },
+        {
+          "name": "feeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [102, 101, 101, 95, 97, 99, 99, 111, 117, 110, 116]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "wrapTokens",
+      "discriminator": [244, 137, 57, 251, 232, 224, 54, 14],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridgeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [98, 114, 105, 100, 103, 101, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "restrictedTokenMint"
+        },
+        {
+          "name": "userRestrictedTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hookMetadata",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [104, 111, 111, 107, 95, 109, 101, 116, 97, 100, 97, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "bridgeTokenMint",
+          "writable": true
+        },
+        {
+          "name": "userBridgeTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "bridgeTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "feeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [102, 101, 101, 95, 97, 99, 99, 111, 117, 110, 116]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userQuota",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [117, 115, 101, 114, 95, 113, 117, 111, 116, 97]
+              },
+              {
+                "kind": "account",
+                "path": "tokenVault"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelist",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [119, 104, 105, 116, 101, 108, 105, 115, 116]
+              },
+              {
+                "kind": "account",
+                "path": "restrictedTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "kycAttestation",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "token2022Program"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminMultisig",
+      "discriminator": [119, 16, 144, 23, 23, 147, 25, 220]
+    },
+    {
+      "name": "adminProposal",
+      "discriminator": [107, 249, 66, 11, 147, 28, 12, 239]
+    },
+    {
+      "name": "bridgeConfig",
+      "discriminator": [40, 206, 51, 233, 246, 40, 178, 85]
+    },
+    {
+      "name": "bridgeRoles",
+      "discriminator": [10, 176, 167, 34, 131, 165, 232, 67]
+    },
+    {
+      "name": "hookMetadata",
+      "discriminator": [119, 227, 48, 236, 101, 194, 218, 85]
+    },
+    {
+      "name": "simpleWhitelist",
+      "discriminator": [69, 202, 246, 224, 255, 143, 157, 186]
+    },
+    {
+      "name": "tokenVault",
+      "discriminator": [121, 7, 84, 254, 151, 228, 43, 144]
+    },
+    {
+      "name": "userQuota",
+      "discriminator": [13, 187, 230, 152, 188, 193, 181, 54]
+    }
+  ],
+  "types": [
+    {
+      "name": "adminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "addApprovedHookProgram",
+            "fields": [
+              {
+                "name": "hookProgramId",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "removeApprovedHookProgram",
+            "fields": [
+              {
+                "name": "hookProgramId",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "proposeAuthority",
+            "fields": [
+              {
+                "name": "newAuthority",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "cancelAuthorityTransfer"
+          },
+          {
+            "name": "unpause",
+            "fields": [
+              {
+                "name": "pauseFlags",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "unpauseVault",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "pauseFlags",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "setAdmissionOverrides",
+            "fields": [
+              {
+                "name": "admissionOverrides",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "setPermissionlessVaultRegistration",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "updateMultisig",
+            "fields": [
+              {
+                "name": "signers",
+                "type": {
+                  "vec": "pubkey"
+                }
+              },
+              {
+                "name": "threshold",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "setTimelockConfig",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              },
+              {
+                "name": "exemptions",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "setVaultEnabled",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "updateHookMetadata",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "hookType",
+                "type": {
+                  "defined": {
+                    "name": "hookType"
+                  }
+                }
+              },
+              {
+                "name": "isActive",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "setTreasury",
+            "fields": [
+              {
+                "name": "treasury",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "setVaultLimits",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "limits",
+                "type": {
+                  "defined": {
+                    "name": "vaultLimits"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setVaultRateLimits",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "wrap",
+                "type": {
+                  "defined": {
+                    "name": "rateLimitConfig"
+                  }
+                }
+              },
+              {
+                "name": "unwrap",
+                "type": {
+                  "defined": {
+                    "name": "rateLimitConfig"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setFeeConfig",
+            "fields": [
+              {
+                "name": "defaultWrapFeeBps",
+                "type": "u16"
+              },
+              {
+                "name": "defaultUnwrapFeeBps",
+                "type": "u16"
+              },
+              {
+                "name": "maxFeeBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "setVaultFees",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "wrapFeeBps",
+                "type": {
+                  "option": "u16"
+                }
+              },
+              {
+                "name": "unwrapFeeBps",
+                "type": {
+                  "option": "u16"
+                }
+              }
+            ]
+          },
+          {
+            "name": "grantRole",
+            "fields": [
+              {
+                "name": "member",
+                "type": "pubkey"
+              },
+              {
+                "name": "roles",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "revokeRole",
+            "fields": [
+              {
+                "name": "member",
+                "type": "pubkey"
+              },
+              {
+                "name": "roles",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "reconcileVaultSurplus",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "mode",
+                "type": {
+                  "defined": {
+                    "name": "surplusMode"
+                  }
+                }
+              },
+              {
+                "name": "destination",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "setVaultUserQuotas",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "userQuotas",
+                "type": {
+                  "defined": {
+                    "name": "userQuotaConfig"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setUserQuota",
+            "fields": [
+              {
+                "name": "tokenVault",
+                "type": "pubkey"
+              },
+              {
+                "name": "user",
+                "type": "pubkey"
+              },
+              {
+                "name": "wrapQuota",
+                "type": {
+                  "option": "u64"
+                }
+              },
+              {
+                "name": "unwrapQuota",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "removeMultisig"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminMultisig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bridgeConfig",
+            "type": "pubkey"
+          },
+          {
+            "name": "signers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          },
+          {
+            "name": "signerSetVersion",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "eta",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          },
+          {
+            "name": "cancelled",
+            "type": "bool"
+          },
+          {
+            "name": "signerSetVersion",
+            "type": "u64"
+          },
+          {
+            "name": "timelockDelay",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposalApproved",
+      "type": {
+        "fields": [
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "approver",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "adminProposalCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "guardian",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "adminProposalCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "adminProposalExecuted",
+      "type": {
+        "fields": [
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "adminProposalQueued",
+      "type": {
+        "fields": [
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          },
+          {
+            "name": "eta",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "admissionOverridesUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "admissionOverrides",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "authorityChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "oldAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "authorityTransferCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "authorityTransferProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "approvedHookPrograms",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "totalLockedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauseReason",
+            "type": "u16"
+          },
+          {
+            "name": "admissionOverrides",
+            "type": "u8"
+          },
+          {
+            "name": "permissionlessVaultRegistration",
+            "type": "bool"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "multisig",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timelockDelay",
+            "type": "i64"
+          },
+          {
+            "name": "timelockExemptions",
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "defaultWrapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "defaultUnwrapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "legacyBridgeTokenMint",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeConfigMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "bridgeConfig",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "legacyBridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalLockedAmount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bridgeInitialized",
+      "type": {
+        "fields": [
+          {
+            "name": "bridgeConfig",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "bridgeRoles",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bridgeConfig",
+            "type": "pubkey"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "roleAssignment"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeTokenMetadataUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "bridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "feeConfigUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "defaultWrapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "defaultUnwrapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxFeeBps",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "feesWithdrawn",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "bridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "hookApproved",
+      "type": {
+        "fields": [
+          {
+            "name": "hookProgramId",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvedCount",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "hookMetadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "hookProgramId",
+            "type": "pubkey"
+          },
+          {
+            "name": "extraAccountMetaList",
+            "type": "pubkey"
+          },
+          {
+            "name": "hookType",
+            "type": {
+              "defined": {
+                "name": "hookType"
+              }
+            }
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "hookMetadataUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "hookMetadata",
+            "type": "pubkey"
+          },
+          {
+            "name": "hookType",
+            "type": {
+              "defined": {
+                "name": "hookType"
+              }
+            }
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "hookRemoved",
+      "type": {
+        "fields": [
+          {
+            "name": "hookProgramId",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvedCount",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "hookType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "whitelist"
+          },
+          {
+            "name": "kyc"
+          },
+          {
+            "name": "transferLimit"
+          },
+          {
+            "name": "custom"
+          }
+        ]
+      }
+    },
+    {
+      "name": "legacyTokensRedeemed",
+      "type": {
+        "fields": [
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "legacyBridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "burned",
+            "type": "u64"
+          },
+          {
+            "name": "transferFee",
+            "type": "u64"
+          },
+          {
+            "name": "received",
+            "type": "u64"
+          },
+          {
+            "name": "legacyLocked",
+            "type": "u64"
+          },
+          {
+            "name": "vaultTotalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeTotalLocked",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "multisigRemoved",
+      "type": {
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "multisigUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "pubkey"
+          },
+          {
+            "name": "signers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "pauseStateChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauseReason",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "rateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "rateLimitConfig"
+              }
+            }
+          },
+          {
+            "name": "available",
+            "type": "u64"
+          },
+          {
+            "name": "lastRefill",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rateLimitConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "capacity",
+            "type": "u64"
+          },
+          {
+            "name": "refillPerSecond",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reservesVerified",
+      "type": {
+        "fields": [
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultBalance",
+            "type": "u64"
+          },
+          {
+            "name": "totalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeSupply",
+            "type": "u64"
+          },
+          {
+            "name": "fullyBacked",
+            "type": "bool"
+          },
+          {
+            "name": "safeMode",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "roleAssignment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "roles",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roleGranted",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "granted",
+            "type": "u8"
+          },
+          {
+            "name": "roles",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "roleRevoked",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "revoked",
+            "type": "u8"
+          },
+          {
+            "name": "roles",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "simpleWhitelist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "users",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "surplusMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "sweepToTreasury"
+          },
+          {
+            "name": "mintToRecipient"
+          }
+        ]
+      }
+    },
+    {
+      "name": "timelockConfigUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "delay",
+            "type": "i64"
+          },
+          {
+            "name": "exemptions",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "tokenVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bridgeConfig",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultTokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "bridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "hookProgramId",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "extensionsBitmap",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "isEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauseReason",
+            "type": "u16"
+          },
+          {
+            "name": "wrapFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "unwrapFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": {
+                "name": "vaultLimits"
+              }
+            }
+          },
+          {
+            "name": "wrapRateLimit",
+            "type": {
+              "defined": {
+                "name": "rateLimit"
+              }
+            }
+          },
+          {
+            "name": "unwrapRateLimit",
+            "type": {
+              "defined": {
+                "name": "rateLimit"
+              }
+            }
+          },
+          {
+            "name": "userQuotas",
+            "type": {
+              "defined": {
+                "name": "userQuotaConfig"
+              }
+            }
+          },
+          {
+            "name": "legacyLocked",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenVaultMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "legacyLocked",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "treasuryUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "unwrapped",
+      "type": {
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "burned",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeFee",
+            "type": "u64"
+          },
+          {
+            "name": "transferFee",
+            "type": "u64"
+          },
+          {
+            "name": "received",
+            "type": "u64"
+          },
+          {
+            "name": "vaultTotalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeTotalLocked",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "userQuota",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "windowStart",
+            "type": "i64"
+          },
+          {
+            "name": "wrapped",
+            "type": "u64"
+          },
+          {
+            "name": "unwrapped",
+            "type": "u64"
+          },
+          {
+            "name": "wrapQuota",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "unwrapQuota",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userQuotaConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dailyWrapQuota",
+            "type": "u64"
+          },
+          {
+            "name": "dailyUnwrapQuota",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userQuotaUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "wrapQuota",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "unwrapQuota",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultFeesUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "wrapFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "unwrapFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTotalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "minWrapAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxWrapAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minUnwrapAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxUnwrapAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultLimitsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": {
+                "name": "vaultLimits"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultPauseStateChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauseReason",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultRateLimitsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "wrap",
+            "type": {
+              "defined": {
+                "name": "rateLimitConfig"
+              }
+            }
+          },
+          {
+            "name": "unwrap",
+            "type": {
+              "defined": {
+                "name": "rateLimitConfig"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultRegistered",
+      "type": {
+        "fields": [
+          {
+            "name": "registrar",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultTokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "hookProgramId",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "hookType",
+            "type": {
+              "defined": {
+                "name": "hookType"
+              }
+            }
+          },
+          {
+            "name": "extensionsBitmap",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultRegistrationModeUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "permissionless",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultStatusChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "isEnabled",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultSurplusMinted",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipientTokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "surplus",
+            "type": "u64"
+          },
+          {
+            "name": "vaultTotalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeTotalLocked",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultSurplusSwept",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasuryTokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "surplus",
+            "type": "u64"
+          },
+          {
+            "name": "transferFee",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "vaultUserQuotasUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "userQuotas",
+            "type": {
+              "defined": {
+                "name": "userQuotaConfig"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistAuthorityChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistAuthorityTransferCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistAuthorityTransferProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistInitialized",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistStatusChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistUserAdded",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalUsers",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "whitelistUserRemoved",
+      "type": {
+        "fields": [
+          {
+            "name": "whitelist",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalUsers",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "wrapped",
+      "type": {
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "restrictedTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bridgeTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "transferFee",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeFee",
+            "type": "u64"
+          },
+          {
+            "name": "minted",
+            "type": "u64"
+          },
+          {
+            "name": "vaultTotalLocked",
+            "type": "u64"
+          },
+          {
+            "name": "bridgeTotalLocked",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [184, 77, 64, 67, 98, 106, 109, 124],
+      "name": "adminProposalApproved"
+    },
+    {
+      "discriminator": [158, 7, 69, 243, 15, 126, 0, 184],
+      "name": "adminProposalCancelled"
+    },
+    {
+      "discriminator": [26, 201, 39, 134, 209, 40, 200, 41],
+      "name": "adminProposalCreated"
+    },
+    {
+      "discriminator": [16, 228, 88, 95, 202, 177, 91, 25],
+      "name": "adminProposalExecuted"
+    },
+    {
+      "discriminator": [159, 152, 192, 139, 249, 5, 183, 201],
+      "name": "adminProposalQueued"
+    },
+    {
+      "discriminator": [92, 111, 91, 213, 12, 156, 87, 84],
+      "name": "admissionOverridesUpdated"
+    },
+    {
+      "discriminator": [31, 19, 174, 152, 4, 82, 215, 226],
+      "name": "authorityChanged"
+    },
+    {
+      "discriminator": [31, 228, 187, 148, 20, 99, 237, 48],
+      "name": "authorityTransferCancelled"
+    },
+    {
+      "discriminator": [103, 244, 27, 116, 177, 4, 100, 119],
+      "name": "authorityTransferProposed"
+    },
+    {
+      "discriminator": [199, 10, 135, 65, 65, 20, 7, 55],
+      "name": "bridgeConfigMigrated"
+    },
+    {
+      "discriminator": [250, 214, 32, 18, 141, 171, 114, 43],
+      "name": "bridgeInitialized"
+    },
+    {
+      "discriminator": [208, 8, 86, 31, 82, 242, 108, 182],
+      "name": "bridgeTokenMetadataUpdated"
+    },
+    {
+      "discriminator": [45, 50, 42, 173, 193, 67, 52, 244],
+      "name": "feeConfigUpdated"
+    },
+    {
+      "discriminator": [234, 15, 0, 119, 148, 241, 40, 21],
+      "name": "feesWithdrawn"
+    },
+    {
+      "discriminator": [154, 189, 167, 216, 190, 171, 211, 206],
+      "name": "hookApproved"
+    },
+    {
+      "discriminator": [99, 173, 25, 168, 85, 131, 161, 136],
+      "name": "hookMetadataUpdated"
+    },
+    {
+      "discriminator": [81, 39, 213, 15, 171, 37, 28, 78],
+      "name": "hookRemoved"
+    },
+    {
+      "discriminator": [185, 200, 128, 15, 107, 118, 64, 1],
+      "name": "legacyTokensRedeemed"
+    },
+    {
+      "discriminator": [117, 69, 102, 175, 164, 91, 42, 92],
+      "name": "multisigRemoved"
+    },
+    {
+      "discriminator": [242, 206, 37, 59, 122, 197, 210, 72],
+      "name": "multisigUpdated"
+    },
+    {
+      "discriminator": [224, 2, 23, 9, 225, 156, 4, 72],
+      "name": "pauseStateChanged"
+    },
+    {
+      "discriminator": [136, 253, 223, 178, 42, 144, 140, 75],
+      "name": "reservesVerified"
+    },
+    {
+      "discriminator": [220, 183, 89, 228, 143, 63, 246, 58],
+      "name": "roleGranted"
+    },
+    {
+      "discriminator": [167, 183, 52, 229, 126, 206, 62, 61],
+      "name": "roleRevoked"
+    },
+    {
+      "discriminator": [185, 151, 233, 144, 250, 169, 17, 4],
+      "name": "timelockConfigUpdated"
+    },
+    {
+      "discriminator": [27, 223, 106, 161, 93, 246, 35, 37],
+      "name": "tokenVaultMigrated"
+    },
+    {
+      "discriminator": [80, 239, 54, 168, 43, 38, 85, 145],
+      "name": "treasuryUpdated"
+    },
+    {
+      "discriminator": [25, 86, 93, 80, 145, 113, 86, 93],
+      "name": "unwrapped"
+    },
     {
-      "name": "bridgeConfig",
-      "discriminator": [40, 206, 51, 233, 246, 40, 178, 85]
+      "discriminator": [150, 250, 12, 19, 139, 32, 224, 158],
+      "name": "userQuotaUpdated"
     },
     {
-      "name": "simpleWhitelist",
-      "discriminator": [69, 202, 246, 224, 255, 143, 157, 186]
+      "discriminator": [251, 221, 126, 24, 30, 216, 100, 207],
+      "name": "vaultFeesUpdated"
     },
     {
-      "name": "tokenVault",
-      "discriminator": [121, 7, 84, 254, 151, 228, 43, 144]
+      "discriminator": [112, 68, 221, 230, 42, 84, 252, 43],
+      "name": "vaultLimitsUpdated"
+    },
+    {
+      "discriminator": [91, 106, 84, 146, 220, 179, 117, 161],
+      "name": "vaultPauseStateChanged"
+    },
+    {
+      "discriminator": [252, 18, 93, 53, 84, 78, 226, 25],
+      "name": "vaultRateLimitsUpdated"
+    },
+    {
+      "discriminator": [215, 55, 28, 170, 12, 58, 41, 93],
+      "name": "vaultRegistered"
+    },
+    {
+      "discriminator": [145, 105, 69, 141, 151, 254, 25, 5],
+      "name": "vaultRegistrationModeUpdated"
+    },
+    {
+      "discriminator": [154, 211, 55, 218, 107, 9, 239, 215],
+      "name": "vaultStatusChanged"
+    },
+    {
+      "discriminator": [133, 116, 128, 5, 78, 74, 30, 87],
+      "name": "vaultSurplusMinted"
+    },
+    {
+      "discriminator": [233, 224, 81, 171, 13, 108, 211, 2],
+      "name": "vaultSurplusSwept"
+    },
+    {
+      "discriminator": [127, 115, 16, 62, 122, 238, 247, 2],
+      "name": "vaultUserQuotasUpdated"
+    },
+    {
+      "discriminator": [206, 145, 150, 169, 237, 243, 62, 49],
+      "name": "whitelistAuthorityChanged"
+    },
+    {
+      "discriminator": [57, 147, 112, 49, 142, 218, 157, 46],
+      "name": "whitelistAuthorityTransferCancelled"
+    },
+    {
+      "discriminator": [144, 47, 169, 17, 198, 141, 107, 40],
+      "name": "whitelistAuthorityTransferProposed"
+    },
+    {
+      "discriminator": [201, 207, 224, 154, 156, 186, 221, 6],
+      "name": "whitelistInitialized"
+    },
+    {
+      "discriminator": [218, 233, 107, 119, 117, 131, 191, 127],
+      "name": "whitelistStatusChanged"
+    },
+    {
+      "discriminator": [205, 155, 13, 160, 60, 61, 234, 118],
+      "name": "whitelistUserAdded"
+    },
+    {
+      "discriminator": [125, 173, 48, 146, 96, 36, 175, 245],
+      "name": "whitelistUserRemoved"
+    },
+    {
+      "discriminator": [11, 127, 145, 31, 206, 134, 73, 130],
+      "name": "wrapped"
     }
   ],
   "errors": [
@@ -710,106 +5974,256 @@ export const IDL = {
       "code": 6016,
       "name": "isNotCurrentlyTransferring",
       "msg": "The token is not currently transferring"
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "bridgeConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "type": "pubkey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "bridgeTokenMint",
-            "type": "pubkey"
-          },
-          {
-            "name": "approvedHookPrograms",
-            "type": {
-              "vec": "pubkey"
-            }
-          },
-          {
-            "name": "totalLockedAmount",
-            "type": "u64"
-          },
-          {
-            "name": "isActive",
-            "type": "bool"
-          }
-        ]
-      }
+      "code": 6017,
+      "name": "extraAccountMetaInitFailed",
+      "msg": "ExtraAccountMeta list initialization failed"
     },
     {
-      "name": "simpleWhitelist",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "type": "pubkey"
-          },
-          {
-            "name": "users",
-            "type": {
-              "vec": "pubkey"
-            }
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+      "code": 6018,
+      "name": "insufficientAccountData",
+      "msg": "Insufficient account data for operation"
     },
     {
-      "name": "tokenVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bridgeConfig",
-            "type": "pubkey"
-          },
-          {
-            "name": "restrictedTokenMint",
-            "type": "pubkey"
-          },
-          {
-            "name": "vaultTokenAccount",
-            "type": "pubkey"
-          },
-          {
-            "name": "bridgeTokenMint",
-            "type": "pubkey"
-          },
-          {
-            "name": "totalLocked",
-            "type": "u64"
-          },
-          {
-            "name": "hookProgramId",
-            "type": {
-              "option": "pubkey"
-            }
-          },
-          {
-            "name": "extensionsBitmap",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+      "code": 6019,
+      "name": "bridgeTokenDecimalsMismatch",
+      "msg": "Bridge token decimals do not match the restricted mint"
+    },
+    {
+      "code": 6020,
+      "name": "missingTransferHookAccounts",
+      "msg": "Transfer hook program or its extra accounts were not provided"
+    },
+    {
+      "code": 6021,
+      "name": "permanentDelegateNotAllowed",
+      "msg": "Mint has a permanent delegate that could drain the vault"
+    },
+    {
+      "code": 6022,
+      "name": "freezeAuthorityNotAllowed",
+      "msg": "Mint can freeze the vault token account"
+    },
+    {
+      "code": 6023,
+      "name": "confidentialOnlyMint",
+      "msg": "Mint supply is confidential-only and cannot be moved by a public transfer"
+    },
+    {
+      "code": 6024,
+      "name": "vaultDisabled",
+      "msg": "Token vault is disabled"
+    },
+    {
+      "code": 6025,
+      "name": "hookSuspended",
+      "msg": "Hook validation is suspended for this vault"
+    },
+    {
+      "code": 6026,
+      "name": "invalidWhitelistAccount",
+      "msg": "Whitelist account does not belong to this mint"
+    },
+    {
+      "code": 6027,
+      "name": "kycAttestationMissing",
+      "msg": "KYC attestation is missing or invalid"
+    },
+    {
+      "code": 6028,
+      "name": "whitelistAccountRequired",
+      "msg": "Whitelist account is required for this vault"
+    },
+    {
+      "code": 6029,
+      "name": "recipientNotWhitelisted",
+      "msg": "Recipient is not whitelisted"
+    },
+    {
+      "code": 6030,
+      "name": "bridgeHookNotSupported",
+      "msg": "Mints using this program's own transfer hook cannot be bridged"
+    },
+    {
+      "code": 6031,
+      "name": "noPendingAuthority",
+      "msg": "No authority transfer is pending"
+    },
+    {
+      "code": 6032,
+      "name": "invalidAuthority",
+      "msg": "Invalid new authority"
+    },
+    {
+      "code": 6033,
+      "name": "missingRole",
+      "msg": "Signer does not hold the role required for this action"
+    },
+    {
+      "code": 6034,
+      "name": "maxRoleMembersReached",
+      "msg": "Maximum role members reached"
+    },
+    {
+      "code": 6035,
+      "name": "invalidRole",
+      "msg": "Invalid role bits"
+    },
+    {
+      "code": 6036,
+      "name": "multisigRequired",
+      "msg": "This action requires an approved admin multisig proposal"
+    },
+    {
+      "code": 6037,
+      "name": "invalidMultisigConfig",
+      "msg": "Invalid multisig signers or threshold"
+    },
+    {
+      "code": 6038,
+      "name": "notMultisigSigner",
+      "msg": "Signer is not a multisig member"
+    },
+    {
+      "code": 6039,
+      "name": "alreadyApproved",
+      "msg": "Signer already approved this proposal"
+    },
+    {
+      "code": 6040,
+      "name": "proposalAlreadyExecuted",
+      "msg": "Proposal has already been executed"
+    },
+    {
+      "code": 6041,
+      "name": "thresholdNotReached",
+      "msg": "Proposal has not reached the approval threshold"
+    },
+    {
+      "code": 6042,
+      "name": "timelockRequired",
+      "msg": "This action must be queued through the governance timelock"
+    },
+    {
+      "code": 6043,
+      "name": "timelockNotElapsed",
+      "msg": "Timelock delay has not elapsed"
+    },
+    {
+      "code": 6044,
+      "name": "proposalCancelled",
+      "msg": "Proposal has been cancelled"
+    },
+    {
+      "code": 6045,
+      "name": "proposalStale",
+      "msg": "Multisig signers or timelock delay changed since the proposal was created"
+    },
+    {
+      "code": 6046,
+      "name": "invalidTimelockConfig",
+      "msg": "Invalid timelock delay or exemptions"
+    },
+    {
+      "code": 6047,
+      "name": "proposalTargetMismatch",
+      "msg": "Account does not match the proposal's target vault"
+    },
+    {
+      "code": 6048,
+      "name": "proposalNotExecutable",
+      "msg": "This proposal is carried out by its own instruction, not execute_admin_proposal"
+    },
+    {
+      "code": 6049,
+      "name": "wrapPaused",
+      "msg": "Wrapping is paused"
+    },
+    {
+      "code": 6050,
+      "name": "unwrapPaused",
+      "msg": "Unwrapping is paused"
+    },
+    {
+      "code": 6051,
+      "name": "vaultRegistrationPaused",
+      "msg": "Vault registration is paused"
+    },
+    {
+      "code": 6052,
+      "name": "invalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6053,
+      "name": "invalidProgramData",
+      "msg": "Program data account does not belong to this program"
+    },
+    {
+      "code": 6054,
+      "name": "noVaultSurplus",
+      "msg": "Vault holds no surplus over its locked amount"
+    },
+    {
+      "code": 6055,
+      "name": "treasuryNotSet",
+      "msg": "Treasury is not configured"
+    },
+    {
+      "code": 6056,
+      "name": "missingSurplusDestination",
+      "msg": "Destination account required for this surplus mode"
+    },
+    {
+      "code": 6057,
+      "name": "feeTooHigh",
+      "msg": "Fee exceeds the configured maximum"
+    },
+    {
+      "code": 6058,
+      "name": "vaultCapExceeded",
+      "msg": "Wrap would exceed the vault's total locked cap"
+    },
+    {
+      "code": 6059,
+      "name": "amountBelowMinimum",
+      "msg": "Amount is below the vault minimum"
+    },
+    {
+      "code": 6060,
+      "name": "amountAboveMaximum",
+      "msg": "Amount is above the vault maximum"
+    },
+    {
+      "code": 6061,
+      "name": "invalidVaultLimits",
+      "msg": "Invalid vault limits"
+    },
+    {
+      "code": 6062,
+      "name": "rateLimitExceeded",
+      "msg": "Vault rate limit exceeded, try again later"
+    },
+    {
+      "code": 6063,
+      "name": "userQuotaExceeded",
+      "msg": "Daily user quota exceeded"
+    },
+    {
+      "code": 6064,
+      "name": "proposalNotCancellable",
+      "msg": "Proposals that replace governance keys cannot be cancelled"
+    },
+    {
+      "code": 6065,
+      "name": "timelockActive",
+      "msg": "Disable the governance timelock before removing the multisig"
+    },
+    {
+      "code": 6066,
+      "name": "notLegacyAccount",
+      "msg": "Account does not have the layout of a pre-upgrade deployment"
     }
   ]
 } as const;
@@ -824,17 +6238,59 @@ export function getProgram(connection: Connection, wallet: AnchorWallet): Progra
     preflightCommitment: "confirmed",
   });
   
-  return new Program(IDL as Idl, PROGRAM_ID, provider);
+  // The IDL carries the program address
+  return new Program(IDL as Idl, provider);
+}
+
+// PauseFlags bits, set on the bridge config and on each vault
+export const PAUSE_FLAGS = {
+  WRAP: 1 << 0,
+  UNWRAP: 1 << 1,
+  REGISTRATION: 1 << 2,
+} as const;
+
+export function isPaused(account: { pauseFlags: number }, flag: number): boolean {
+  return (account.pauseFlags & flag) !== 0;
 }
 
 // Account types for TypeScript
 export interface BridgeConfig {
   authority: PublicKey;
   bump: number;
-  bridgeTokenMint: PublicKey;
   approvedHookPrograms: PublicKey[];
   totalLockedAmount: BN;
-  isActive: boolean;
+  pauseFlags: number;
+  pauseReason: number;
+  admissionOverrides: number;
+  permissionlessVaultRegistration: boolean;
+  pendingAuthority: PublicKey | null;
+  multisig: PublicKey | null;
+  timelockDelay: BN;
+  timelockExemptions: number;
+  treasury: PublicKey | null;
+  defaultWrapFeeBps: number;
+  defaultUnwrapFeeBps: number;
+  maxFeeBps: number;
+  legacyBridgeTokenMint: PublicKey | null;
+}
+
+export interface VaultLimits {
+  maxTotalLocked: BN;
+  minWrapAmount: BN;
+  maxWrapAmount: BN;
+  minUnwrapAmount: BN;
+  maxUnwrapAmount: BN;
+}
+
+export interface RateLimit {
+  config: { capacity: BN; refillPerSecond: BN };
+  available: BN;
+  lastRefill: BN;
+}
+
+export interface UserQuotaConfig {
+  dailyWrapQuota: BN;
+  dailyUnwrapQuota: BN;
 }
 
 export interface TokenVault {
@@ -846,12 +6302,40 @@ export interface TokenVault {
   hookProgramId: PublicKey | null;
   extensionsBitmap: BN;
   bump: number;
+  isEnabled: boolean;
+  pauseFlags: number;
+  pauseReason: number;
+  wrapFeeBps: number | null;
+  unwrapFeeBps: number | null;
+  limits: VaultLimits;
+  wrapRateLimit: RateLimit;
+  unwrapRateLimit: RateLimit;
+  userQuotas: UserQuotaConfig;
+  legacyLocked: BN;
+}
+
+export type HookType =
+  | { whitelist: {} }
+  | { kyc: {} }
+  | { transferLimit: {} }
+  | { custom: {} };
+
+export interface HookMetadata {
+  vault: PublicKey;
+  hookProgramId: PublicKey;
+  extraAccountMetaList: PublicKey;
+  hookType: HookType;
+  isActive: boolean;
+  bump: number;
 }
 
 export interface SimpleWhitelist {
   authority: PublicKey;
+  mint: PublicKey;
   users: PublicKey[];
+  isActive: boolean;
   bump: number;
+  pendingAuthority: PublicKey | null;
 }
 
 // Helper functions for type conversion
@@ -859,10 +6343,21 @@ export function convertBridgeConfig(raw: any): BridgeConfig {
   return {
     authority: raw.authority,
     bump: raw.bump,
-    bridgeTokenMint: raw.bridgeTokenMint,
     approvedHookPrograms: raw.approvedHookPrograms,
     totalLockedAmount: raw.totalLockedAmount,
-    isActive: raw.isActive,
+    pauseFlags: raw.pauseFlags,
+    pauseReason: raw.pauseReason,
+    admissionOverrides: raw.admissionOverrides,
+    permissionlessVaultRegistration: raw.permissionlessVaultRegistration,
+    pendingAuthority: raw.pendingAuthority,
+    multisig: raw.multisig,
+    timelockDelay: raw.timelockDelay,
+    timelockExemptions: raw.timelockExemptions,
+    treasury: raw.treasury,
+    defaultWrapFeeBps: raw.defaultWrapFeeBps,
+    defaultUnwrapFeeBps: raw.defaultUnwrapFeeBps,
+    maxFeeBps: raw.maxFeeBps,
+    legacyBridgeTokenMint: raw.legacyBridgeTokenMint,
   };
 }
 
@@ -876,14 +6371,38 @@ export function convertTokenVault(raw: any): TokenVault {
     hookProgramId: raw.hookProgramId,
     extensionsBitmap: raw.extensionsBitmap,
     bump: raw.bump,
+    isEnabled: raw.isEnabled,
+    pauseFlags: raw.pauseFlags,
+    pauseReason: raw.pauseReason,
+    wrapFeeBps: raw.wrapFeeBps,
+    unwrapFeeBps: raw.unwrapFeeBps,
+    limits: raw.limits,
+    wrapRateLimit: raw.wrapRateLimit,
+    unwrapRateLimit: raw.unwrapRateLimit,
+    userQuotas: raw.userQuotas,
+    legacyLocked: raw.legacyLocked,
+  };
+}
+
+export function convertHookMetadata(raw: any): HookMetadata {
+  return {
+    vault: raw.vault,
+    hookProgramId: raw.hookProgramId,
+    extraAccountMetaList: raw.extraAccountMetaList,
+    hookType: raw.hookType,
+    isActive: raw.isActive,
+    bump: raw.bump,
   };
 }
 
 export function convertSimpleWhitelist(raw: any): SimpleWhitelist {
   return {
     authority: raw.authority,
+    mint: raw.mint,
     users: raw.users,
+    isActive: raw.isActive,
     bump: raw.bump,
+    pendingAuthority: raw.pendingAuthority,
   };
 }
 
@@ -906,6 +6425,56 @@ export enum BridgeError {
   MathOverflow = 6014,
   SenderNotWhitelisted = 6015,
   IsNotCurrentlyTransferring = 6016,
+  ExtraAccountMetaInitFailed = 6017,
+  InsufficientAccountData = 6018,
+  BridgeTokenDecimalsMismatch = 6019,
+  MissingTransferHookAccounts = 6020,
+  PermanentDelegateNotAllowed = 6021,
+  FreezeAuthorityNotAllowed = 6022,
+  ConfidentialOnlyMint = 6023,
+  VaultDisabled = 6024,
+  HookSuspended = 6025,
+  InvalidWhitelistAccount = 6026,
+  KycAttestationMissing = 6027,
+  WhitelistAccountRequired = 6028,
+  RecipientNotWhitelisted = 6029,
+  BridgeHookNotSupported = 6030,
+  NoPendingAuthority = 6031,
+  InvalidAuthority = 6032,
+  MissingRole = 6033,
+  MaxRoleMembersReached = 6034,
+  InvalidRole = 6035,
+  MultisigRequired = 6036,
+  InvalidMultisigConfig = 6037,
+  NotMultisigSigner = 6038,
+  AlreadyApproved = 6039,
+  ProposalAlreadyExecuted = 6040,
+  ThresholdNotReached = 6041,
+  TimelockRequired = 6042,
+  TimelockNotElapsed = 6043,
+  ProposalCancelled = 6044,
+  ProposalStale = 6045,
+  InvalidTimelockConfig = 6046,
+  ProposalTargetMismatch = 6047,
+  ProposalNotExecutable = 6048,
+  WrapPaused = 6049,
+  UnwrapPaused = 6050,
+  VaultRegistrationPaused = 6051,
+  InvalidPauseFlags = 6052,
+  InvalidProgramData = 6053,
+  NoVaultSurplus = 6054,
+  TreasuryNotSet = 6055,
+  MissingSurplusDestination = 6056,
+  FeeTooHigh = 6057,
+  VaultCapExceeded = 6058,
+  AmountBelowMinimum = 6059,
+  AmountAboveMaximum = 6060,
+  InvalidVaultLimits = 6061,
+  RateLimitExceeded = 6062,
+  UserQuotaExceeded = 6063,
+  ProposalNotCancellable = 6064,
+  TimelockActive = 6065,
+  NotLegacyAccount = 6066,
 }
 
 // Error message mapping
@@ -927,6 +6496,56 @@ export const ERROR_MESSAGES: Record<BridgeError, string> = {
   [BridgeError.MathOverflow]: "Math overflow error",
   [BridgeError.SenderNotWhitelisted]: "Sender is not whitelisted",
   [BridgeError.IsNotCurrentlyTransferring]: "The token is not currently transferring",
+  [BridgeError.ExtraAccountMetaInitFailed]: "ExtraAccountMeta list initialization failed",
+  [BridgeError.InsufficientAccountData]: "Insufficient account data for operation",
+  [BridgeError.BridgeTokenDecimalsMismatch]: "Bridge token decimals do not match the restricted mint",
+  [BridgeError.MissingTransferHookAccounts]: "Transfer hook program or its extra accounts were not provided",
+  [BridgeError.PermanentDelegateNotAllowed]: "Mint has a permanent delegate that could drain the vault",
+  [BridgeError.FreezeAuthorityNotAllowed]: "Mint can freeze the vault token account",
+  [BridgeError.ConfidentialOnlyMint]: "Mint supply is confidential-only and cannot be moved by a public transfer",
+  [BridgeError.VaultDisabled]: "Token vault is disabled",
+  [BridgeError.HookSuspended]: "Hook validation is suspended for this vault",
+  [BridgeError.InvalidWhitelistAccount]: "Whitelist account does not belong to this mint",
+  [BridgeError.KycAttestationMissing]: "KYC attestation is missing or invalid",
+  [BridgeError.WhitelistAccountRequired]: "Whitelist account is required for this vault",
+  [BridgeError.RecipientNotWhitelisted]: "Recipient is not whitelisted",
+  [BridgeError.BridgeHookNotSupported]: "Mints using this program's own transfer hook cannot be bridged",
+  [BridgeError.NoPendingAuthority]: "No authority transfer is pending",
+  [BridgeError.InvalidAuthority]: "Invalid new authority",
+  [BridgeError.MissingRole]: "Signer does not hold the role required for this action",
+  [BridgeError.MaxRoleMembersReached]: "Maximum role members reached",
+  [BridgeError.InvalidRole]: "Invalid role bits",
+  [BridgeError.MultisigRequired]: "This action requires an approved admin multisig proposal",
+  [BridgeError.InvalidMultisigConfig]: "Invalid multisig signers or threshold",
+  [BridgeError.NotMultisigSigner]: "Signer is not a multisig member",
+  [BridgeError.AlreadyApproved]: "Signer already approved this proposal",
+  [BridgeError.ProposalAlreadyExecuted]: "Proposal has already been executed",
+  [BridgeError.ThresholdNotReached]: "Proposal has not reached the approval threshold",
+  [BridgeError.TimelockRequired]: "This action must be queued through the governance timelock",
+  [BridgeError.TimelockNotElapsed]: "Timelock delay has not elapsed",
+  [BridgeError.ProposalCancelled]: "Proposal has been cancelled",
+  [BridgeError.ProposalStale]: "Multisig signers or timelock delay changed since the proposal was created",
+  [BridgeError.InvalidTimelockConfig]: "Invalid timelock delay or exemptions",
+  [BridgeError.ProposalTargetMismatch]: "Account does not match the proposal's target vault",
+  [BridgeError.ProposalNotExecutable]: "This proposal is carried out by its own instruction, not execute_admin_proposal",
+  [BridgeError.WrapPaused]: "Wrapping is paused",
+  [BridgeError.UnwrapPaused]: "Unwrapping is paused",
+  [BridgeError.VaultRegistrationPaused]: "Vault registration is paused",
+  [BridgeError.InvalidPauseFlags]: "Invalid pause flags",
+  [BridgeError.InvalidProgramData]: "Program data account does not belong to this program",
+  [BridgeError.NoVaultSurplus]: "Vault holds no surplus over its locked amount",
+  [BridgeError.TreasuryNotSet]: "Treasury is not configured",
+  [BridgeError.MissingSurplusDestination]: "Destination account required for this surplus mode",
+  [BridgeError.FeeTooHigh]: "Fee exceeds the configured maximum",
+  [BridgeError.VaultCapExceeded]: "Wrap would exceed the vault's total locked cap",
+  [BridgeError.AmountBelowMinimum]: "Amount is below the vault minimum",
+  [BridgeError.AmountAboveMaximum]: "Amount is above the vault maximum",
+  [BridgeError.InvalidVaultLimits]: "Invalid vault limits",
+  [BridgeError.RateLimitExceeded]: "Vault rate limit exceeded, try again later",
+  [BridgeError.UserQuotaExceeded]: "Daily user quota exceeded",
+  [BridgeError.ProposalNotCancellable]: "Proposals that replace governance keys cannot be cancelled",
+  [BridgeError.TimelockActive]: "Disable the governance timelock before removing the multisig",
+  [BridgeError.NotLegacyAccount]: "Account does not have the layout of a pre-upgrade deployment",
 };
 
 // Helper function to get error message
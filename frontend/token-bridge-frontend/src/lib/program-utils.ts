@@ -9,6 +9,7 @@ import {
 
 // Updated program ID for new deployment
 const PROGRAM_ID = new PublicKey("Hfvd4ZLYac9wHs8fz4Yo3DCNqU1qRScMY4tu9GwQP7gw");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
 
 // PDA derivation functions based on your actual program seeds
 
@@ -34,6 +35,61 @@ export function getTokenVaultPDA(restrictedTokenMint: PublicKey): [PublicKey, nu
   );
 }
 
+/**
+ * Get Bridge Roles PDA
+ * Seeds: ["bridge_roles"]
+ */
+export function getBridgeRolesPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bridge_roles")],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get Bridge Token Mint PDA (one standard SPL mint per restricted mint)
+ * Seeds: ["bridge_token_mint", restrictedTokenMint]
+ */
+export function getBridgeTokenMintPDA(restrictedTokenMint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bridge_token_mint"), restrictedTokenMint.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get Hook Metadata PDA
+ * Seeds: ["hook_metadata", tokenVault]
+ */
+export function getHookMetadataPDA(tokenVault: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("hook_metadata"), tokenVault.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get Fee Account PDA (bridge token account collecting wrap/unwrap fees)
+ * Seeds: ["fee_account", tokenVault]
+ */
+export function getFeeAccountPDA(tokenVault: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_account"), tokenVault.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get User Quota PDA (per-user daily counters, created on first use)
+ * Seeds: ["user_quota", tokenVault, user]
+ */
+export function getUserQuotaPDA(tokenVault: PublicKey, user: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("user_quota"), tokenVault.toBuffer(), user.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 /**
  * Get Whitelist PDA
  * Seeds: ["whitelist", mint]
@@ -121,15 +177,18 @@ export function getUserBridgeTokenAccount(
 // Account structure helpers
 
 /**
- * Get all accounts needed for wrap_tokens instruction
+ * Get all accounts needed for wrap_tokens instruction.
+ * `whitelist` and `kycAttestation` are only checked for vaults with a
+ * whitelist or KYC hook; pass null otherwise.
  */
 export function getWrapTokensAccounts(
   user: PublicKey,
   restrictedTokenMint: PublicKey,
-  bridgeTokenMint: PublicKey
+  hookAccounts: { whitelist?: PublicKey | null; kycAttestation?: PublicKey | null } = {}
 ) {
   const [bridgeConfig] = getBridgeConfigPDA();
   const [tokenVault] = getTokenVaultPDA(restrictedTokenMint);
+  const [bridgeTokenMint] = getBridgeTokenMintPDA(restrictedTokenMint);
   
   return {
     user,
@@ -137,9 +196,14 @@ export function getWrapTokensAccounts(
     restrictedTokenMint,
     userRestrictedTokenAccount: getUserRestrictedTokenAccount(restrictedTokenMint, user),
     tokenVault,
+    hookMetadata: getHookMetadataPDA(tokenVault)[0],
     vaultTokenAccount: getVaultTokenAccount(restrictedTokenMint, tokenVault),
     bridgeTokenMint,
     userBridgeTokenAccount: getUserBridgeTokenAccount(bridgeTokenMint, user),
+    feeAccount: getFeeAccountPDA(tokenVault)[0],
+    userQuota: getUserQuotaPDA(tokenVault, user)[0],
+    whitelist: hookAccounts.whitelist ?? null,
+    kycAttestation: hookAccounts.kycAttestation ?? null,
     tokenProgram: TOKEN_PROGRAM_ID,
     token2022Program: TOKEN_2022_PROGRAM_ID,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -148,25 +212,34 @@ export function getWrapTokensAccounts(
 }
 
 /**
- * Get all accounts needed for unwrap_tokens instruction
+ * Get all accounts needed for unwrap_tokens instruction.
+ * The restricted tokens go to `recipient`, the user by default.
  */
 export function getUnwrapTokensAccounts(
   user: PublicKey,
   restrictedTokenMint: PublicKey,
-  bridgeTokenMint: PublicKey
+  recipient: PublicKey = user,
+  hookAccounts: { whitelist?: PublicKey | null; kycAttestation?: PublicKey | null } = {}
 ) {
   const [bridgeConfig] = getBridgeConfigPDA();
   const [tokenVault] = getTokenVaultPDA(restrictedTokenMint);
+  const [bridgeTokenMint] = getBridgeTokenMintPDA(restrictedTokenMint);
   
   return {
     user,
+    recipient,
     bridgeConfig,
     restrictedTokenMint,
-    userRestrictedTokenAccount: getUserRestrictedTokenAccount(restrictedTokenMint, user),
+    recipientTokenAccount: getUserRestrictedTokenAccount(restrictedTokenMint, recipient),
     tokenVault,
+    hookMetadata: getHookMetadataPDA(tokenVault)[0],
     vaultTokenAccount: getVaultTokenAccount(restrictedTokenMint, tokenVault),
     bridgeTokenMint,
     userBridgeTokenAccount: getUserBridgeTokenAccount(bridgeTokenMint, user),
+    feeAccount: getFeeAccountPDA(tokenVault)[0],
+    userQuota: getUserQuotaPDA(tokenVault, user)[0],
+    whitelist: hookAccounts.whitelist ?? null,
+    kycAttestation: hookAccounts.kycAttestation ?? null,
     tokenProgram: TOKEN_PROGRAM_ID,
     token2022Program: TOKEN_2022_PROGRAM_ID,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -207,46 +280,39 @@ export function getManageWhitelistAccounts(
   };
 }
 
-/**
- * Get all accounts needed for create_bridge_token_mint instruction
- */
-export function getCreateBridgeTokenMintAccounts(
-  authority: PublicKey,
-  bridgeTokenMint: PublicKey
-) {
-  const [bridgeConfig] = getBridgeConfigPDA();
-  
-  return {
-    authority,
-    bridgeConfig,
-    bridgeTokenMint,
-    tokenProgram: TOKEN_PROGRAM_ID,
-    systemProgram: SystemProgram.programId,
-  };
-}
-
 /**
  * Get all accounts needed for initialize_bridge instruction
  */
 export function getInitializeBridgeAccounts(authority: PublicKey) {
   const [bridgeConfig] = getBridgeConfigPDA();
+  const [bridgeRoles] = getBridgeRolesPDA();
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
   
+  // Only the program's upgrade authority can initialize
   return {
     authority,
     bridgeConfig,
+    bridgeRoles,
+    bridgeProgram: PROGRAM_ID,
+    programData,
     systemProgram: SystemProgram.programId,
   };
 }
 
 /**
- * Get all accounts needed for admin operations (toggle, update authority, etc.)
+ * Get all accounts needed for authority-only admin operations (hook approval, etc.)
  */
-export function getUpdateBridgeConfigAccounts(authority: PublicKey) {
+export function getUpdateBridgeConfigAccounts(admin: PublicKey) {
   const [bridgeConfig] = getBridgeConfigPDA();
+  const [bridgeRoles] = getBridgeRolesPDA();
   
   return {
-    authority,
+    admin,
     bridgeConfig,
+    bridgeRoles,
   };
 }
 
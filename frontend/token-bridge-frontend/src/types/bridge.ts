@@ -6,15 +6,20 @@ import * as anchor from "@coral-xyz/anchor";
 export type {
   BridgeConfig,
   TokenVault,
+  HookMetadata,
+  HookType,
   SimpleWhitelist,
 } from '@/lib/anchor-setup';
 
 export {
   BridgeError,
   ERROR_MESSAGES,
+  PAUSE_FLAGS,
+  isPaused,
   getErrorMessage,
   convertBridgeConfig,
   convertTokenVault,
+  convertHookMetadata,
   convertSimpleWhitelist,
 } from '@/lib/anchor-setup';
 
@@ -120,7 +125,7 @@ export interface TokenSelectorProps {
 // Hook return types
 export interface UseBridgeReturn {
   wrapTokens: (mint: PublicKey, amount: number, decimals?: number) => Promise<string>;
-  unwrapTokens: (mint: PublicKey, amount: number, decimals?: number) => Promise<string>;
+  unwrapTokens: (mint: PublicKey, amount: number, decimals?: number, recipient?: PublicKey) => Promise<string>;
   isLoading: boolean;
   error: string | null;
 }
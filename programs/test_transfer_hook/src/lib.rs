@@ -0,0 +1,130 @@
+#![allow(unexpected_cfgs)]
+// Only for `__idl_resize_account`, see the bridge program's crate root.
+#![allow(deprecated)]
+
+//! Transfer hook used by the bridge test suites. Every transfer of a mint
+//! hooked to this program must carry the mint's counter as an extra account,
+//! and the hook records the transfer there, so a transfer that succeeds
+//! proves the extra accounts reached the hook.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+
+declare_id!("BBfrgzs46M5iSW1MNDfpkegCbXvRAxhx7sE6TErW3FLH");
+
+#[program]
+pub mod test_transfer_hook {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        ctx.accounts.counter.bump = ctx.bumps.counter;
+
+        let account_metas = InitializeExtraAccountMetaList::extra_account_metas()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &account_metas,
+        )?;
+
+        msg!("Transfer counter initialized for mint: {}", ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => execute(program_id, accounts, amount),
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TransferCounter {
+    pub transfers: u64,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: ExtraAccountMeta list account - initialized in this instruction
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(1)?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TransferCounter::INIT_SPACE,
+        seeds = [b"counter", mint.key().as_ref()],
+        bump
+    )]
+    pub counter: Account<'info, TransferCounter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeExtraAccountMetaList<'_> {
+    pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+        Ok(vec![
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal {
+                    bytes: b"counter".to_vec(),
+                }, Seed::AccountKey { index: 1 }], // mint account index
+                false, // is_signer
+                true,  // is_writable
+            )?,
+        ])
+    }
+}
+
+#[error_code]
+pub enum TestHookError {
+    #[msg("Transfer counter does not belong to the mint")]
+    InvalidCounter,
+}
+
+fn execute<'info>(
+    program_id: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    // Source, mint, destination, owner, validation account, counter
+    require!(accounts.len() >= 6, ErrorCode::AccountNotEnoughKeys);
+    let mint = &accounts[1];
+    let counter_info = &accounts[5];
+
+    let mut counter = Account::<TransferCounter>::try_from(counter_info)?;
+    let expected_counter = Pubkey::create_program_address(
+        &[b"counter", mint.key().as_ref(), &[counter.bump]],
+        program_id,
+    ).map_err(|_| TestHookError::InvalidCounter)?;
+    require_keys_eq!(counter_info.key(), expected_counter, TestHookError::InvalidCounter);
+
+    counter.transfers = counter.transfers.checked_add(1).unwrap();
+    counter.amount = counter.amount.checked_add(amount).unwrap();
+    counter.exit(program_id)?;
+
+    msg!("Transfer hook recorded transfer {} of {}", counter.transfers, amount);
+    Ok(())
+}
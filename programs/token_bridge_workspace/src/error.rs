@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum BridgeError {
+    // Deprecated, no longer raised: pausing is reported by `WrapPaused` and
+    // friends. Retired variants stay in place so every later error keeps its
+    // code (6000 + index); new errors go at the end.
     #[msg("Bridge is not active")]
     BridgeNotActive,
     
@@ -38,6 +41,7 @@ pub enum BridgeError {
     #[msg("Transfer fee calculation failed")]
     TransferFeeCalculationFailed,
     
+    // Deprecated, no longer raised
     #[msg("Hook validation failed during unwrap")]
     HookValidationFailed,
     
@@ -53,9 +57,155 @@ pub enum BridgeError {
     #[msg("The token is not currently transferring")]
     IsNotCurrentlyTransferring,
     
+    // Deprecated, no longer raised
     #[msg("ExtraAccountMeta list initialization failed")]
     ExtraAccountMetaInitFailed,
     
+    // Deprecated, no longer raised
     #[msg("Insufficient account data for operation")]
     InsufficientAccountData,
+    
+    #[msg("Bridge token decimals do not match the restricted mint")]
+    BridgeTokenDecimalsMismatch,
+    
+    #[msg("Transfer hook program or its extra accounts were not provided")]
+    MissingTransferHookAccounts,
+    
+    #[msg("Mint has a permanent delegate that could drain the vault")]
+    PermanentDelegateNotAllowed,
+    
+    #[msg("Mint can freeze the vault token account")]
+    FreezeAuthorityNotAllowed,
+    
+    #[msg("Mint supply is confidential-only and cannot be moved by a public transfer")]
+    ConfidentialOnlyMint,
+    
+    #[msg("Token vault is disabled")]
+    VaultDisabled,
+    
+    #[msg("Hook validation is suspended for this vault")]
+    HookSuspended,
+    
+    #[msg("Whitelist account does not belong to this mint")]
+    InvalidWhitelistAccount,
+    
+    #[msg("KYC attestation is missing or invalid")]
+    KycAttestationMissing,
+    
+    #[msg("Whitelist account is required for this vault")]
+    WhitelistAccountRequired,
+    
+    #[msg("Recipient is not whitelisted")]
+    RecipientNotWhitelisted,
+    
+    #[msg("Mints using this program's own transfer hook cannot be bridged")]
+    BridgeHookNotSupported,
+    
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    
+    #[msg("Invalid new authority")]
+    InvalidAuthority,
+    
+    #[msg("Signer does not hold the role required for this action")]
+    MissingRole,
+    
+    #[msg("Maximum role members reached")]
+    MaxRoleMembersReached,
+    
+    #[msg("Invalid role bits")]
+    InvalidRole,
+    
+    #[msg("This action requires an approved admin multisig proposal")]
+    MultisigRequired,
+    
+    #[msg("Invalid multisig signers or threshold")]
+    InvalidMultisigConfig,
+    
+    #[msg("Signer is not a multisig member")]
+    NotMultisigSigner,
+    
+    #[msg("Signer already approved this proposal")]
+    AlreadyApproved,
+    
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Proposal has not reached the approval threshold")]
+    ThresholdNotReached,
+    
+    #[msg("This action must be queued through the governance timelock")]
+    TimelockRequired,
+    
+    #[msg("Timelock delay has not elapsed")]
+    TimelockNotElapsed,
+    
+    #[msg("Proposal has been cancelled")]
+    ProposalCancelled,
+    
+    #[msg("Multisig signers or timelock delay changed since the proposal was created")]
+    ProposalStale,
+    
+    #[msg("Invalid timelock delay or exemptions")]
+    InvalidTimelockConfig,
+    
+    #[msg("Account does not match the proposal's target vault")]
+    ProposalTargetMismatch,
+    
+    #[msg("This proposal is carried out by its own instruction, not execute_admin_proposal")]
+    ProposalNotExecutable,
+    
+    #[msg("Wrapping is paused")]
+    WrapPaused,
+    
+    #[msg("Unwrapping is paused")]
+    UnwrapPaused,
+    
+    #[msg("Vault registration is paused")]
+    VaultRegistrationPaused,
+    
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Program data account does not belong to this program")]
+    InvalidProgramData,
+    
+    #[msg("Vault holds no surplus over its locked amount")]
+    NoVaultSurplus,
+    
+    #[msg("Treasury is not configured")]
+    TreasuryNotSet,
+    
+    #[msg("Destination account required for this surplus mode")]
+    MissingSurplusDestination,
+    
+    #[msg("Fee exceeds the configured maximum")]
+    FeeTooHigh,
+    
+    #[msg("Wrap would exceed the vault's total locked cap")]
+    VaultCapExceeded,
+    
+    #[msg("Amount is below the vault minimum")]
+    AmountBelowMinimum,
+    
+    #[msg("Amount is above the vault maximum")]
+    AmountAboveMaximum,
+    
+    #[msg("Invalid vault limits")]
+    InvalidVaultLimits,
+    
+    #[msg("Vault rate limit exceeded, try again later")]
+    RateLimitExceeded,
+    
+    #[msg("Daily user quota exceeded")]
+    UserQuotaExceeded,
+    
+    #[msg("Proposals that replace governance keys cannot be cancelled")]
+    ProposalNotCancellable,
+    
+    #[msg("Disable the governance timelock before removing the multisig")]
+    TimelockActive,
+    
+    #[msg("Account does not have the layout of a pre-upgrade deployment")]
+    NotLegacyAccount,
 }
\ No newline at end of file
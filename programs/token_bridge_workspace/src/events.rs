@@ -0,0 +1,383 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminAction, HookType, RateLimitConfig, UserQuotaConfig, VaultLimits};
+
+#[event]
+pub struct BridgeInitialized {
+    pub bridge_config: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct VaultRegistered {
+    pub registrar: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub bridge_token_mint: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub hook_program_id: Option<Pubkey>,
+    pub hook_type: HookType,
+    pub extensions_bitmap: u64,
+}
+
+#[event]
+pub struct BridgeTokenMetadataUpdated {
+    pub token_vault: Pubkey,
+    pub bridge_token_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct Wrapped {
+    pub user: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub bridge_token_mint: Pubkey,
+    pub amount: u64,                         // Restricted tokens sent by the user
+    pub transfer_fee: u64,                   // Withheld by Token-2022 on the way in
+    pub bridge_fee: u64,                     // Bridge tokens accrued to the fee account
+    pub minted: u64,                         // Bridge tokens minted to the user
+    pub vault_total_locked: u64,
+    pub bridge_total_locked: u64,
+}
+
+#[event]
+pub struct Unwrapped {
+    pub user: Pubkey,
+    pub recipient: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub bridge_token_mint: Pubkey,
+    pub burned: u64,                         // Bridge tokens burned from the user
+    pub bridge_fee: u64,                     // Bridge tokens moved to the fee account
+    pub transfer_fee: u64,                   // Withheld by Token-2022 on the way out
+    pub received: u64,                       // Restricted tokens credited to the recipient
+    pub vault_total_locked: u64,
+    pub bridge_total_locked: u64,
+}
+
+#[event]
+pub struct ReservesVerified {
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub vault_balance: u64,                  // Restricted tokens held by the vault ATA
+    pub total_locked: u64,                   // TokenVault.total_locked counter
+    pub bridge_supply: u64,                  // Outstanding bridge tokens
+    pub fully_backed: bool,
+    pub safe_mode: bool,                     // Vault is now unwrap-only
+}
+
+#[event]
+pub struct VaultSurplusSwept {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub surplus: u64,
+    pub transfer_fee: u64,                   // Withheld by Token-2022 on the way out
+}
+
+#[event]
+pub struct VaultSurplusMinted {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub surplus: u64,                        // Bridge tokens minted against the surplus
+    pub vault_total_locked: u64,
+    pub bridge_total_locked: u64,
+}
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub authority: Pubkey,
+    pub default_wrap_fee_bps: u16,
+    pub default_unwrap_fee_bps: u16,
+    pub max_fee_bps: u16,
+}
+
+#[event]
+pub struct VaultFeesUpdated {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub wrap_fee_bps: Option<u16>,
+    pub unwrap_fee_bps: Option<u16>,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub bridge_token_mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct HookApproved {
+    pub hook_program_id: Pubkey,
+    pub approved_count: u8,
+}
+
+#[event]
+pub struct HookRemoved {
+    pub hook_program_id: Pubkey,
+    pub approved_count: u8,
+}
+
+#[event]
+pub struct PauseStateChanged {
+    pub authority: Pubkey,
+    pub previous_flags: u8,
+    pub pause_flags: u8,
+    pub pause_reason: u16,
+}
+
+#[event]
+pub struct VaultPauseStateChanged {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub previous_flags: u8,
+    pub pause_flags: u8,
+    pub pause_reason: u16,
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub authority: Pubkey,
+    pub member: Pubkey,
+    pub granted: u8,
+    pub roles: u8,                           // Role bits held after the change
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub authority: Pubkey,
+    pub member: Pubkey,
+    pub revoked: u8,
+    pub roles: u8,                           // Role bits held after the change
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigRemoved {
+    pub multisig: Pubkey,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct AdminProposalExecuted {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct AdminProposalQueued {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub eta: i64,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct TimelockConfigUpdated {
+    pub authority: Pubkey,
+    pub delay: i64,
+    pub exemptions: u16,
+}
+
+#[event]
+pub struct AdmissionOverridesUpdated {
+    pub authority: Pubkey,
+    pub admission_overrides: u8,
+}
+
+#[event]
+pub struct VaultRegistrationModeUpdated {
+    pub authority: Pubkey,
+    pub permissionless: bool,
+}
+
+#[event]
+pub struct VaultStatusChanged {
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub is_enabled: bool,
+}
+
+#[event]
+pub struct VaultLimitsUpdated {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub limits: VaultLimits,
+}
+
+#[event]
+pub struct VaultRateLimitsUpdated {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub wrap: RateLimitConfig,
+    pub unwrap: RateLimitConfig,
+}
+
+#[event]
+pub struct VaultUserQuotasUpdated {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub user_quotas: UserQuotaConfig,
+}
+
+#[event]
+pub struct UserQuotaUpdated {
+    pub authority: Pubkey,
+    pub token_vault: Pubkey,
+    pub user: Pubkey,
+    pub wrap_quota: Option<u64>,
+    pub unwrap_quota: Option<u64>,
+}
+
+#[event]
+pub struct HookMetadataUpdated {
+    pub token_vault: Pubkey,
+    pub hook_metadata: Pubkey,
+    pub hook_type: HookType,
+    pub is_active: bool,
+}
+
+#[event]
+pub struct WhitelistInitialized {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct WhitelistUserAdded {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub total_users: u8,
+}
+
+#[event]
+pub struct WhitelistUserRemoved {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub total_users: u8,
+}
+
+#[event]
+pub struct WhitelistAuthorityTransferProposed {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct WhitelistAuthorityChanged {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct WhitelistAuthorityTransferCancelled {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub cancelled_authority: Pubkey,
+}
+
+#[event]
+pub struct WhitelistStatusChanged {
+    pub whitelist: Pubkey,
+    pub mint: Pubkey,
+    pub is_active: bool,
+}
+
+#[event]
+pub struct BridgeConfigMigrated {
+    pub bridge_config: Pubkey,
+    pub authority: Pubkey,
+    pub legacy_bridge_token_mint: Pubkey,
+    pub total_locked_amount: u64,
+}
+
+#[event]
+pub struct TokenVaultMigrated {
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub legacy_locked: u64,
+}
+
+#[event]
+pub struct LegacyTokensRedeemed {
+    pub holder: Pubkey,
+    pub token_vault: Pubkey,
+    pub restricted_token_mint: Pubkey,
+    pub legacy_bridge_token_mint: Pubkey,
+    pub burned: u64,
+    pub transfer_fee: u64,
+    pub received: u64,
+    pub legacy_locked: u64,
+    pub vault_total_locked: u64,
+    pub bridge_total_locked: u64,
+}
@@ -1,42 +1,169 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{update_metadata_accounts_v2, Metadata, UpdateMetadataAccountsV2},
+    token_interface::Mint,
+};
 use crate::state::*;
 use crate::error::*;
+use crate::events::*;
+use crate::utils::{bridge_token_metadata, require_role};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateBridgeConfig<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+    
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptBridgeAuthority<'info> {
+    pub pending_authority: Signer<'info>,
     
     #[account(
         mut,
         seeds = [b"bridge_config"],
         bump = bridge_config.bump,
-        has_one = authority @ BridgeError::Unauthorized
+        constraint = bridge_config.pending_authority.is_some() @ BridgeError::NoPendingAuthority,
+        constraint = bridge_config.pending_authority == Some(pending_authority.key()) @ BridgeError::Unauthorized
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTokenVault<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+    
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+    
+    #[account(
+        mut,
+        seeds = [b"token_vault", token_vault.restricted_token_mint.as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateHookMetadata<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+    
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+    
+    #[account(
+        seeds = [b"token_vault", token_vault.restricted_token_mint.as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"hook_metadata", token_vault.key().as_ref()],
+        bump = hook_metadata.bump
+    )]
+    pub hook_metadata: Account<'info, HookMetadata>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefreshBridgeTokenMetadata<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+    
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+    
+    #[account(
+        seeds = [b"token_vault", token_vault.restricted_token_mint.as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    #[account(address = token_vault.restricted_token_mint @ BridgeError::InvalidTokenVault)]
+    pub restricted_token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(address = token_vault.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch)]
+    pub bridge_token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Metaplex metadata PDA of the bridge mint
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), bridge_token_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub bridge_token_metadata: UncheckedAccount<'info>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
 pub fn add_approved_hook_program(
     ctx: Context<UpdateBridgeConfig>, 
     hook_program_id: Pubkey
 ) -> Result<()> {
-    let bridge_config = &mut ctx.accounts.bridge_config;
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::HOOK_MANAGER,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::ADD_HOOK_PROGRAM)?;
     
-    require!(
-        bridge_config.approved_hook_programs.len() < BridgeConfig::MAX_APPROVED_HOOKS,
-        BridgeError::MaxApprovedHooksReached
-    );
-    
-    require!(
-        !bridge_config.approved_hook_programs.contains(&hook_program_id),
-        BridgeError::HookProgramAlreadyApproved
-    );
-    
-    bridge_config.approved_hook_programs.push(hook_program_id);
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    approve_hook_program(bridge_config, hook_program_id)?;
     
     msg!("Added approved hook program: {}", hook_program_id);
     msg!("Total approved hook programs: {}", bridge_config.approved_hook_programs.len());
     
+    emit_cpi!(HookApproved {
+        hook_program_id,
+        approved_count: bridge_config.approved_hook_programs.len() as u8,
+    });
+    
     Ok(())
 }
 
@@ -44,11 +171,24 @@ pub fn remove_approved_hook_program(
     ctx: Context<UpdateBridgeConfig>, 
     hook_program_id: Pubkey
 ) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::HOOK_MANAGER,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::REMOVE_HOOK_PROGRAM)?;
+    
     let bridge_config = &mut ctx.accounts.bridge_config;
     
-    if let Some(pos) = bridge_config.approved_hook_programs.iter().position(|&x| x == hook_program_id) {
-        bridge_config.approved_hook_programs.remove(pos);
+    if unapprove_hook_program(bridge_config, hook_program_id) {
         msg!("Removed approved hook program: {}", hook_program_id);
+        
+        emit_cpi!(HookRemoved {
+            hook_program_id,
+            approved_count: bridge_config.approved_hook_programs.len() as u8,
+        });
     } else {
         msg!("Hook program not found in approved list: {}", hook_program_id);
     }
@@ -56,26 +196,581 @@ pub fn remove_approved_hook_program(
     Ok(())
 }
 
-pub fn toggle_bridge_status(ctx: Context<UpdateBridgeConfig>) -> Result<()> {
+/// Adds `pause_flags` to the bridge-wide pause state and records
+/// `pause_reason`. Flags already set stay set, so concurrent pausers cannot
+/// undo each other; repeating the current state is a no-op.
+pub fn pause(
+    ctx: Context<UpdateBridgeConfig>, 
+    pause_flags: u8,
+    pause_reason: u16
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::PAUSER,
+    )?;
+    require!(
+        pause_flags != 0 && pause_flags & !PauseFlags::ALL == 0,
+        BridgeError::InvalidPauseFlags
+    );
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let previous_flags = bridge_config.pause_flags;
+    if !bridge_config.pause(pause_flags, pause_reason) {
+        msg!("Bridge pause state unchanged: {:#b}", previous_flags);
+        return Ok(());
+    }
+    
+    msg!("Bridge pause flags changed from {:#b} to {:#b}", previous_flags, bridge_config.pause_flags);
+    msg!("Pause reason: {}", pause_reason);
+    
+    emit_cpi!(PauseStateChanged {
+        authority: ctx.accounts.admin.key(),
+        previous_flags,
+        pause_flags: bridge_config.pause_flags,
+        pause_reason,
+    });
+    
+    Ok(())
+}
+
+/// Clears `pause_flags` from the bridge-wide pause state, leaving any other
+/// flag in place. Needs a super-admin and is subject to the multisig and
+/// timelock.
+pub fn unpause(ctx: Context<UpdateBridgeConfig>, pause_flags: u8) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::UNPAUSE)?;
+    require!(
+        pause_flags != 0 && pause_flags & !PauseFlags::ALL == 0,
+        BridgeError::InvalidPauseFlags
+    );
+    
     let bridge_config = &mut ctx.accounts.bridge_config;
+    let previous_flags = bridge_config.pause_flags;
+    if !bridge_config.unpause(pause_flags) {
+        msg!("Bridge pause state unchanged: {:#b}", previous_flags);
+        return Ok(());
+    }
+    
+    msg!("Bridge pause flags changed from {:#b} to {:#b}", previous_flags, bridge_config.pause_flags);
+    
+    emit_cpi!(PauseStateChanged {
+        authority: ctx.accounts.admin.key(),
+        previous_flags,
+        pause_flags: bridge_config.pause_flags,
+        pause_reason: bridge_config.pause_reason,
+    });
+    
+    Ok(())
+}
+
+/// Per-vault counterpart of `pause` for the WRAP and UNWRAP flags.
+pub fn pause_vault(
+    ctx: Context<UpdateTokenVault>, 
+    pause_flags: u8,
+    pause_reason: u16
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::PAUSER,
+    )?;
+    require!(
+        pause_flags != 0 && pause_flags & !PauseFlags::VAULT == 0,
+        BridgeError::InvalidPauseFlags
+    );
     
-    bridge_config.is_active = !bridge_config.is_active;
+    let token_vault = &mut ctx.accounts.token_vault;
+    let previous_flags = token_vault.pause_flags;
+    if !token_vault.pause(pause_flags, pause_reason) {
+        msg!("Vault pause state unchanged: {:#b}", previous_flags);
+        return Ok(());
+    }
     
-    msg!("Bridge status changed to: {}", bridge_config.is_active);
+    msg!("Vault for mint {} pause flags: {:#b}", token_vault.restricted_token_mint, token_vault.pause_flags);
+    msg!("Pause reason: {}", pause_reason);
+    
+    emit_cpi!(VaultPauseStateChanged {
+        authority: ctx.accounts.admin.key(),
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        previous_flags,
+        pause_flags: token_vault.pause_flags,
+        pause_reason,
+    });
     
     Ok(())
 }
 
-pub fn update_bridge_authority(
+/// Per-vault counterpart of `unpause`. Needs a vault manager and respects
+/// the timelock.
+pub fn unpause_vault(ctx: Context<UpdateTokenVault>, pause_flags: u8) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::VAULT_MANAGER,
+    )?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::VAULT_POLICY)?;
+    require!(
+        pause_flags != 0 && pause_flags & !PauseFlags::VAULT == 0,
+        BridgeError::InvalidPauseFlags
+    );
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    let previous_flags = token_vault.pause_flags;
+    if !token_vault.unpause(pause_flags) {
+        msg!("Vault pause state unchanged: {:#b}", previous_flags);
+        return Ok(());
+    }
+    
+    msg!("Vault for mint {} pause flags: {:#b}", token_vault.restricted_token_mint, token_vault.pause_flags);
+    
+    emit_cpi!(VaultPauseStateChanged {
+        authority: ctx.accounts.admin.key(),
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        previous_flags,
+        pause_flags: token_vault.pause_flags,
+        pause_reason: token_vault.pause_reason,
+    });
+    
+    Ok(())
+}
+
+pub fn propose_authority(
     ctx: Context<UpdateBridgeConfig>, 
     new_authority: Pubkey
 ) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.bridge_config.authority,
+        BridgeError::Unauthorized
+    );
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::PROPOSE_AUTHORITY)?;
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    set_pending_authority(bridge_config, new_authority)?;
+    
+    msg!("Bridge authority transfer proposed to: {}", new_authority);
+    
+    emit_cpi!(AuthorityTransferProposed {
+        authority: bridge_config.authority,
+        pending_authority: new_authority,
+    });
+    
+    Ok(())
+}
+
+pub fn accept_authority(ctx: Context<AcceptBridgeAuthority>) -> Result<()> {
     let bridge_config = &mut ctx.accounts.bridge_config;
     
     let old_authority = bridge_config.authority;
+    let new_authority = ctx.accounts.pending_authority.key();
     bridge_config.authority = new_authority;
+    bridge_config.pending_authority = None;
     
     msg!("Bridge authority updated from {} to {}", old_authority, new_authority);
     
+    emit_cpi!(AuthorityChanged {
+        old_authority,
+        new_authority,
+    });
+    
+    Ok(())
+}
+
+pub fn cancel_authority_transfer(ctx: Context<UpdateBridgeConfig>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.bridge_config.authority,
+        BridgeError::Unauthorized
+    );
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    
+    let cancelled_authority = clear_pending_authority(bridge_config)?;
+    
+    msg!("Bridge authority transfer to {} cancelled", cancelled_authority);
+    
+    emit_cpi!(AuthorityTransferCancelled {
+        authority: bridge_config.authority,
+        cancelled_authority,
+    });
+    
+    Ok(())
+}
+
+pub fn set_admission_overrides(
+    ctx: Context<UpdateBridgeConfig>, 
+    admission_overrides: u8
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::SET_ADMISSION_OVERRIDES)?;
+    
+    require!(
+        admission_overrides & !AdmissionOverrides::ALL == 0,
+        BridgeError::InvalidExtensionConfig
+    );
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    bridge_config.admission_overrides = admission_overrides;
+    
+    msg!("Mint admission overrides set to: {:#b}", admission_overrides);
+    
+    emit_cpi!(AdmissionOverridesUpdated {
+        authority: ctx.accounts.admin.key(),
+        admission_overrides,
+    });
+    
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn set_permissionless_vault_registration(
+    ctx: Context<UpdateBridgeConfig>, 
+    enabled: bool
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::SET_VAULT_REGISTRATION)?;
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    bridge_config.permissionless_vault_registration = enabled;
+    
+    msg!("Permissionless vault registration set to: {}", enabled);
+    
+    emit_cpi!(VaultRegistrationModeUpdated {
+        authority: ctx.accounts.admin.key(),
+        permissionless: enabled,
+    });
+    
+    Ok(())
+}
+
+pub fn set_vault_enabled(ctx: Context<UpdateTokenVault>, enabled: bool) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::VAULT_MANAGER,
+    )?;
+    // Disabling a vault is an emergency stop and never waits on the timelock.
+    if enabled {
+        require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::VAULT_POLICY)?;
+    }
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.is_enabled = enabled;
+    
+    msg!("Vault for mint {} enabled: {}", token_vault.restricted_token_mint, enabled);
+    
+    emit_cpi!(VaultStatusChanged {
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        is_enabled: enabled,
+    });
+    
+    Ok(())
+}
+
+pub fn set_vault_limits(ctx: Context<UpdateTokenVault>, limits: VaultLimits) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::VAULT_MANAGER,
+    )?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::VAULT_POLICY)?;
+    require!(limits.is_valid(), BridgeError::InvalidVaultLimits);
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.limits = limits;
+    
+    msg!("Vault for mint {} limits: {:?}", token_vault.restricted_token_mint, limits);
+    
+    emit_cpi!(VaultLimitsUpdated {
+        authority: ctx.accounts.admin.key(),
+        token_vault: token_vault.key(),
+        limits,
+    });
+    
+    Ok(())
+}
+
+/// Replaces the vault's wrap and unwrap token buckets; both start full.
+pub fn set_vault_rate_limits(
+    ctx: Context<UpdateTokenVault>, 
+    wrap: RateLimitConfig,
+    unwrap: RateLimitConfig
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::VAULT_MANAGER,
+    )?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::VAULT_POLICY)?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.wrap_rate_limit = RateLimit::new(wrap, now);
+    token_vault.unwrap_rate_limit = RateLimit::new(unwrap, now);
+    
+    msg!("Vault for mint {} rate limits: wrap {:?}, unwrap {:?}", token_vault.restricted_token_mint, wrap, unwrap);
+    
+    emit_cpi!(VaultRateLimitsUpdated {
+        authority: ctx.accounts.admin.key(),
+        token_vault: token_vault.key(),
+        wrap,
+        unwrap,
+    });
+    
+    Ok(())
+}
+
+pub fn update_hook_metadata(
+    ctx: Context<UpdateHookMetadata>, 
+    hook_type: HookType,
+    is_active: bool
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::HOOK_MANAGER,
+    )?;
+    // Suspending a hook is an emergency stop and never waits on the
+    // timelock; reactivating it does.
+    if is_active {
+        require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::VAULT_POLICY)?;
+    }
+    
+    let hook_metadata = &mut ctx.accounts.hook_metadata;
+    hook_metadata.hook_type = hook_type;
+    hook_metadata.is_active = is_active;
+    
+    msg!("Hook metadata updated for vault: {}", hook_metadata.vault);
+    msg!("Hook type: {:?}, active: {}", hook_type, is_active);
+    
+    emit_cpi!(HookMetadataUpdated {
+        token_vault: hook_metadata.vault,
+        hook_metadata: hook_metadata.key(),
+        hook_type,
+        is_active,
+    });
+    
+    Ok(())
+}
+
+/// Re-mirrors the restricted mint's `TokenMetadata` onto the bridge mint's
+/// Metaplex metadata, e.g. after the issuer changed its name or URI.
+pub fn refresh_bridge_token_metadata(ctx: Context<RefreshBridgeTokenMetadata>) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::VAULT_MANAGER,
+    )?;
+    
+    let metadata = bridge_token_metadata(&ctx.accounts.restricted_token_mint.to_account_info())?;
+    let bridge_config_seeds: &[&[u8]] = &[b"bridge_config", &[ctx.accounts.bridge_config.bump]];
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.bridge_token_metadata.to_account_info(),
+                update_authority: ctx.accounts.bridge_config.to_account_info(),
+            },
+            &[bridge_config_seeds],
+        ),
+        None,
+        Some(metadata.clone()),
+        None,
+        None,
+    )?;
+    
+    msg!("Bridge token metadata refreshed: {} ({})", metadata.name, metadata.symbol);
+    
+    emit_cpi!(BridgeTokenMetadataUpdated {
+        token_vault: ctx.accounts.token_vault.key(),
+        bridge_token_mint: ctx.accounts.bridge_token_mint.key(),
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+    });
+    
+    Ok(())
+}
+
+pub fn set_treasury(ctx: Context<UpdateBridgeConfig>, treasury: Pubkey) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::SET_TREASURY)?;
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    bridge_config.treasury = Some(treasury);
+    
+    msg!("Treasury set to: {}", treasury);
+    
+    emit_cpi!(TreasuryUpdated {
+        authority: ctx.accounts.admin.key(),
+        treasury,
+    });
+    
+    Ok(())
+}
+
+/// Configures the governance timelock. A delay needs an admin multisig, and
+/// once one exists every change goes through an
+/// `AdminAction::SetTimelockConfig` proposal.
+pub fn set_timelock_config(
+    ctx: Context<UpdateBridgeConfig>, 
+    delay: i64,
+    exemptions: u16
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    apply_timelock_config(bridge_config, delay, exemptions)?;
+    
+    msg!("Timelock delay set to {}s, exemptions: {:#b}", delay, exemptions);
+    
+    emit_cpi!(TimelockConfigUpdated {
+        authority: ctx.accounts.admin.key(),
+        delay,
+        exemptions,
+    });
+    
+    Ok(())
+}
+
+/// Changing the authority, roles, treasury, fees, timelock or admission
+/// policy, approving or removing hook programs, opening vault registration
+/// and unpausing must go through `execute_admin_proposal` once an admin multisig
+/// is configured.
+pub(crate) fn require_no_multisig(bridge_config: &BridgeConfig) -> Result<()> {
+    require!(bridge_config.multisig.is_none(), BridgeError::MultisigRequired);
+    
+    Ok(())
+}
+
+pub(crate) fn approve_hook_program(
+    bridge_config: &mut BridgeConfig, 
+    hook_program_id: Pubkey
+) -> Result<()> {
+    require!(
+        bridge_config.approved_hook_programs.len() < BridgeConfig::MAX_APPROVED_HOOKS,
+        BridgeError::MaxApprovedHooksReached
+    );
+    
+    require!(
+        !bridge_config.approved_hook_programs.contains(&hook_program_id),
+        BridgeError::HookProgramAlreadyApproved
+    );
+    
+    bridge_config.approved_hook_programs.push(hook_program_id);
+    
+    Ok(())
+}
+
+pub(crate) fn unapprove_hook_program(
+    bridge_config: &mut BridgeConfig, 
+    hook_program_id: Pubkey
+) -> bool {
+    match bridge_config.approved_hook_programs.iter().position(|&x| x == hook_program_id) {
+        Some(pos) => {
+            bridge_config.approved_hook_programs.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn set_pending_authority(
+    bridge_config: &mut BridgeConfig, 
+    new_authority: Pubkey
+) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default() && new_authority != bridge_config.authority,
+        BridgeError::InvalidAuthority
+    );
+    
+    bridge_config.pending_authority = Some(new_authority);
+    
+    Ok(())
+}
+
+pub(crate) fn clear_pending_authority(bridge_config: &mut BridgeConfig) -> Result<Pubkey> {
+    let cancelled_authority = bridge_config.pending_authority
+        .take()
+        .ok_or(BridgeError::NoPendingAuthority)?;
+    
+    Ok(cancelled_authority)
+}
+
+pub(crate) fn require_no_timelock(bridge_config: &BridgeConfig, action: u16) -> Result<()> {
+    require!(
+        !bridge_config.timelock_applies(action),
+        BridgeError::TimelockRequired
+    );
+    
+    Ok(())
+}
+
+/// A delay needs an admin multisig to queue proposals through, otherwise
+/// every non-exempt action would be locked out.
+pub(crate) fn apply_timelock_config(
+    bridge_config: &mut BridgeConfig, 
+    delay: i64,
+    exemptions: u16
+) -> Result<()> {
+    require!(
+        (0..=BridgeConfig::MAX_TIMELOCK_DELAY).contains(&delay),
+        BridgeError::InvalidTimelockConfig
+    );
+    require!(
+        exemptions & !GovernanceActions::ALL == 0
+            && exemptions & GovernanceActions::SET_TIMELOCK == 0,
+        BridgeError::InvalidTimelockConfig
+    );
+    if delay > 0 {
+        require!(bridge_config.multisig.is_some(), BridgeError::MultisigRequired);
+    }
+    
+    bridge_config.timelock_delay = delay;
+    bridge_config.timelock_exemptions = exemptions;
+    
+    Ok(())
+}
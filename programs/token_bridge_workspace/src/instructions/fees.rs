@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::require_role;
+use super::admin::{require_no_multisig, require_no_timelock, UpdateBridgeConfig, UpdateTokenVault};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        seeds = [b"token_vault", token_vault.restricted_token_mint.as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(address = token_vault.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch)]
+    pub bridge_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_account", token_vault.key().as_ref()],
+        bump,
+        token::mint = bridge_token_mint,
+        token::authority = bridge_config
+    )]
+    pub fee_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = bridge_token_mint,
+        constraint = Some(destination.owner) == bridge_config.treasury @ BridgeError::TreasuryNotSet
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn set_fee_config(
+    ctx: Context<UpdateBridgeConfig>,
+    default_wrap_fee_bps: u16,
+    default_unwrap_fee_bps: u16,
+    max_fee_bps: u16,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::SET_FEES)?;
+
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    apply_fee_config(bridge_config, default_wrap_fee_bps, default_unwrap_fee_bps, max_fee_bps)?;
+
+    msg!("Default fees: wrap {} bps, unwrap {} bps", default_wrap_fee_bps, default_unwrap_fee_bps);
+    msg!("Maximum fee: {} bps", max_fee_bps);
+
+    emit_cpi!(FeeConfigUpdated {
+        authority: ctx.accounts.admin.key(),
+        default_wrap_fee_bps,
+        default_unwrap_fee_bps,
+        max_fee_bps,
+    });
+
+    Ok(())
+}
+
+/// Per-vault fee overrides; `None` falls back to the bridge default.
+pub fn set_vault_fees(
+    ctx: Context<UpdateTokenVault>,
+    wrap_fee_bps: Option<u16>,
+    unwrap_fee_bps: Option<u16>,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::FEE_MANAGER,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::SET_FEES)?;
+
+    let token_vault = &mut ctx.accounts.token_vault;
+    apply_vault_fees(&ctx.accounts.bridge_config, token_vault, wrap_fee_bps, unwrap_fee_bps)?;
+
+    msg!("Vault for mint {} fees: wrap {:?}, unwrap {:?}", token_vault.restricted_token_mint, wrap_fee_bps, unwrap_fee_bps);
+
+    emit_cpi!(VaultFeesUpdated {
+        authority: ctx.accounts.admin.key(),
+        token_vault: token_vault.key(),
+        wrap_fee_bps,
+        unwrap_fee_bps,
+    });
+
+    Ok(())
+}
+
+/// Moves accrued fees from a vault's fee account to a token account owned by
+/// the treasury. Fees are paid out in the vault's bridge tokens, not the
+/// underlying restricted token; the treasury unwraps them like any holder,
+/// subject to the vault's hook and transfer fee.
+pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::FEE_MANAGER,
+    )?;
+
+    require!(
+        amount > 0 && amount <= ctx.accounts.fee_account.amount,
+        BridgeError::InvalidBridgeTokenAmount
+    );
+
+    let bridge_signer_seeds: &[&[u8]] = &[
+        b"bridge_config",
+        &[ctx.accounts.bridge_config.bump],
+    ];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_account.to_account_info(),
+                mint: ctx.accounts.bridge_token_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.bridge_config.to_account_info(),
+            },
+            &[bridge_signer_seeds],
+        ),
+        amount,
+        ctx.accounts.bridge_token_mint.decimals,
+    )?;
+
+    msg!("Withdrew {} bridge tokens in fees to {}", amount, ctx.accounts.destination.key());
+
+    emit_cpi!(FeesWithdrawn {
+        authority: ctx.accounts.admin.key(),
+        token_vault: ctx.accounts.token_vault.key(),
+        bridge_token_mint: ctx.accounts.bridge_token_mint.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_fee_config(
+    bridge_config: &mut BridgeConfig,
+    default_wrap_fee_bps: u16,
+    default_unwrap_fee_bps: u16,
+    max_fee_bps: u16,
+) -> Result<()> {
+    require!(
+        max_fee_bps <= BridgeConfig::FEE_BPS_DENOMINATOR,
+        BridgeError::FeeTooHigh
+    );
+    require!(
+        default_wrap_fee_bps <= max_fee_bps && default_unwrap_fee_bps <= max_fee_bps,
+        BridgeError::FeeTooHigh
+    );
+
+    bridge_config.default_wrap_fee_bps = default_wrap_fee_bps;
+    bridge_config.default_unwrap_fee_bps = default_unwrap_fee_bps;
+    bridge_config.max_fee_bps = max_fee_bps;
+
+    Ok(())
+}
+
+pub(crate) fn apply_vault_fees(
+    bridge_config: &BridgeConfig,
+    token_vault: &mut TokenVault,
+    wrap_fee_bps: Option<u16>,
+    unwrap_fee_bps: Option<u16>,
+) -> Result<()> {
+    require!(
+        wrap_fee_bps.unwrap_or(0) <= bridge_config.max_fee_bps
+            && unwrap_fee_bps.unwrap_or(0) <= bridge_config.max_fee_bps,
+        BridgeError::FeeTooHigh
+    );
+
+    token_vault.wrap_fee_bps = wrap_fee_bps;
+    token_vault.unwrap_fee_bps = unwrap_fee_bps;
+
+    Ok(())
+}
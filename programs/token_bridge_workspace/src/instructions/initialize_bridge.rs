@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::*;
+use crate::events::*;
+use crate::program::TokenBridgeWorkspace;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeBridge<'info> {
     #[account(mut)]
@@ -16,6 +19,27 @@ pub struct InitializeBridge<'info> {
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = BridgeRoles::SPACE,
+        seeds = [b"bridge_roles"],
+        bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+    
+    // Only the upgrade authority recorded in the program data account can
+    // initialize, so nobody can claim the bridge between deploy and init.
+    #[account(
+        constraint = bridge_program.programdata_address()? == Some(program_data.key()) @ BridgeError::InvalidProgramData
+    )]
+    pub bridge_program: Program<'info, TokenBridgeWorkspace>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ BridgeError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -24,12 +48,33 @@ pub fn initialize_bridge(ctx: Context<InitializeBridge>) -> Result<()> {
     
     bridge_config.authority = ctx.accounts.authority.key();
     bridge_config.bump = ctx.bumps.bridge_config;
-    bridge_config.bridge_token_mint = Pubkey::default(); 
     bridge_config.approved_hook_programs = Vec::new();
     bridge_config.total_locked_amount = 0;
-    bridge_config.is_active = true;
+    bridge_config.pause_flags = 0;
+    bridge_config.pause_reason = 0;
+    bridge_config.admission_overrides = 0;
+    bridge_config.permissionless_vault_registration = false;
+    bridge_config.pending_authority = None;
+    bridge_config.multisig = None;
+    bridge_config.timelock_delay = 0;
+    bridge_config.timelock_exemptions = 0;
+    bridge_config.treasury = None;
+    bridge_config.default_wrap_fee_bps = 0;
+    bridge_config.default_unwrap_fee_bps = 0;
+    bridge_config.max_fee_bps = 0;
+    bridge_config.legacy_bridge_token_mint = None;
+    
+    let bridge_roles = &mut ctx.accounts.bridge_roles;
+    bridge_roles.bridge_config = bridge_config.key();
+    bridge_roles.members = Vec::new();
+    bridge_roles.bump = ctx.bumps.bridge_roles;
     
     msg!("Bridge initialized with authority: {}", bridge_config.authority);
     
+    emit_cpi!(BridgeInitialized {
+        bridge_config: bridge_config.key(),
+        authority: bridge_config.authority,
+    });
+    
     Ok(())
 }
\ No newline at end of file
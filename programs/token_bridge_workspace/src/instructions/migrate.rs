@@ -0,0 +1,532 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface},
+};
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::{
+    calculate_transfer_fee, detect_mint_extensions, transfer_hook_error, validate_hook_requirements,
+};
+use super::SimpleWhitelist;
+
+/// `BridgeConfig` as written by deployments that still had one shared
+/// bridge mint.
+#[derive(AnchorDeserialize)]
+struct LegacyBridgeConfig {
+    authority: Pubkey,
+    bump: u8,
+    bridge_token_mint: Pubkey,
+    approved_hook_programs: Vec<Pubkey>,
+    total_locked_amount: u64,
+    is_active: bool,
+}
+
+impl LegacyBridgeConfig {
+    const SPACE: usize = 8 + 32 + 1 + 32 + (4 + 32 * BridgeConfig::MAX_APPROVED_HOOKS) + 8 + 1;
+}
+
+/// `TokenVault` as written by deployments that still had one shared bridge
+/// mint, `bridge_token_mint` being that mint.
+#[derive(AnchorDeserialize)]
+struct LegacyTokenVault {
+    bridge_config: Pubkey,
+    restricted_token_mint: Pubkey,
+    vault_token_account: Pubkey,
+    bridge_token_mint: Pubkey,
+    total_locked: u64,
+    _hook_program_id: Option<Pubkey>,
+    _extensions_bitmap: u64,
+    bump: u8,
+}
+
+impl LegacyTokenVault {
+    const SPACE: usize = 8 + 32 + 32 + 32 + 32 + 8 + (1 + 32) + 8 + 1;
+}
+
+/// Reads `account` as `T` if it still has the pre-upgrade size and carries
+/// the discriminator `D` of the account type it became.
+fn read_legacy<T: AnchorDeserialize, D: Discriminator>(
+    account: &AccountInfo,
+    legacy_space: usize,
+) -> Result<T> {
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() == legacy_space && data.starts_with(D::DISCRIMINATOR),
+        BridgeError::NotLegacyAccount
+    );
+
+    Ok(T::deserialize(&mut &data[8..])?)
+}
+
+/// Tops `account` up to rent exemption for `space` bytes and resizes it,
+/// zero-filling the new bytes.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    account.resize(space)?;
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateBridgeConfig<'info> {
+    /// Must be the authority recorded in the legacy config
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: legacy-layout bridge config, read and rewritten by the handler
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump,
+        owner = crate::ID
+    )]
+    pub bridge_config: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BridgeRoles::SPACE,
+        seeds = [b"bridge_roles"],
+        bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Converts a bridge config left by a shared-mint deployment to the current
+/// layout. The shared mint is kept as `legacy_bridge_token_mint` so that its
+/// holders can redeem through `redeem_legacy_tokens`, and an inactive bridge
+/// comes out with wraps and unwraps paused.
+pub fn migrate_bridge_config(ctx: Context<MigrateBridgeConfig>) -> Result<()> {
+    let bridge_config_info = ctx.accounts.bridge_config.to_account_info();
+    let legacy = read_legacy::<LegacyBridgeConfig, BridgeConfig>(
+        &bridge_config_info,
+        LegacyBridgeConfig::SPACE,
+    )?;
+    require_keys_eq!(legacy.authority, ctx.accounts.authority.key(), BridgeError::Unauthorized);
+
+    grow_account(
+        &bridge_config_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BridgeConfig::SPACE,
+    )?;
+
+    let bridge_config = BridgeConfig {
+        authority: legacy.authority,
+        bump: legacy.bump,
+        approved_hook_programs: legacy.approved_hook_programs,
+        total_locked_amount: legacy.total_locked_amount,
+        pause_flags: if legacy.is_active { 0 } else { PauseFlags::WRAP | PauseFlags::UNWRAP },
+        pause_reason: 0,
+        admission_overrides: 0,
+        permissionless_vault_registration: false,
+        pending_authority: None,
+        multisig: None,
+        timelock_delay: 0,
+        timelock_exemptions: 0,
+        treasury: None,
+        default_wrap_fee_bps: 0,
+        default_unwrap_fee_bps: 0,
+        max_fee_bps: 0,
+        legacy_bridge_token_mint: Some(legacy.bridge_token_mint),
+    };
+    bridge_config.try_serialize(&mut &mut bridge_config_info.try_borrow_mut_data()?[..])?;
+
+    let bridge_roles = &mut ctx.accounts.bridge_roles;
+    bridge_roles.bridge_config = bridge_config_info.key();
+    bridge_roles.members = Vec::new();
+    bridge_roles.bump = ctx.bumps.bridge_roles;
+
+    msg!("Bridge config migrated, legacy bridge mint: {}", legacy.bridge_token_mint);
+
+    emit_cpi!(BridgeConfigMigrated {
+        bridge_config: bridge_config_info.key(),
+        authority: bridge_config.authority,
+        legacy_bridge_token_mint: legacy.bridge_token_mint,
+        total_locked_amount: bridge_config.total_locked_amount,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateLegacyVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// CHECK: only used to derive the vault address
+    pub restricted_token_mint: UncheckedAccount<'info>,
+
+    /// CHECK: legacy-layout vault, read and rewritten by the handler
+    #[account(
+        mut,
+        seeds = [b"token_vault", restricted_token_mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub token_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Converts a vault left by a shared-mint deployment to the current layout.
+/// Anyone may call it: the vault comes out unregistered, holding on to its
+/// locked tokens as `legacy_locked`, and `register_vault` then completes it
+/// with its own bridge mint.
+pub fn migrate_legacy_vault(ctx: Context<MigrateLegacyVault>) -> Result<()> {
+    let token_vault_info = ctx.accounts.token_vault.to_account_info();
+    let legacy = read_legacy::<LegacyTokenVault, TokenVault>(
+        &token_vault_info,
+        LegacyTokenVault::SPACE,
+    )?;
+    require_keys_eq!(
+        legacy.bridge_config,
+        ctx.accounts.bridge_config.key(),
+        BridgeError::InvalidTokenVault
+    );
+    require!(
+        ctx.accounts.bridge_config.legacy_bridge_token_mint == Some(legacy.bridge_token_mint),
+        BridgeError::BridgeTokenMintMismatch
+    );
+
+    grow_account(
+        &token_vault_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        TokenVault::SPACE,
+    )?;
+
+    // `bridge_config` and `bridge_token_mint` stay unset until
+    // `register_vault`; until then the vault can neither wrap nor unwrap.
+    let token_vault = TokenVault {
+        bridge_config: Pubkey::default(),
+        restricted_token_mint: legacy.restricted_token_mint,
+        vault_token_account: legacy.vault_token_account,
+        bridge_token_mint: Pubkey::default(),
+        total_locked: legacy.total_locked,
+        hook_program_id: None,
+        extensions_bitmap: 0,
+        bump: legacy.bump,
+        is_enabled: false,
+        pause_flags: 0,
+        pause_reason: 0,
+        wrap_fee_bps: None,
+        unwrap_fee_bps: None,
+        limits: VaultLimits::default(),
+        wrap_rate_limit: RateLimit::default(),
+        unwrap_rate_limit: RateLimit::default(),
+        user_quotas: UserQuotaConfig::default(),
+        legacy_locked: legacy.total_locked,
+    };
+    token_vault.try_serialize(&mut &mut token_vault_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Vault migrated with {} legacy locked tokens", token_vault.legacy_locked);
+
+    emit_cpi!(TokenVaultMigrated {
+        token_vault: token_vault_info.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        legacy_locked: token_vault.legacy_locked,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateLegacyWhitelist<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only used to derive the whitelist address
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: legacy-size whitelist, resized by the handler
+    #[account(
+        mut,
+        seeds = [b"whitelist", mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub whitelist: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Makes room for `SimpleWhitelist.pending_authority` in a whitelist created
+/// before it existed. The new bytes read as no pending authority. Only full
+/// whitelists need it, as a shorter one already has the trailing zero byte.
+pub fn migrate_legacy_whitelist(ctx: Context<MigrateLegacyWhitelist>) -> Result<()> {
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    {
+        let data = whitelist_info.try_borrow_data()?;
+        require!(
+            data.len() == SimpleWhitelist::SPACE - (1 + 32)
+                && data.starts_with(SimpleWhitelist::DISCRIMINATOR),
+            BridgeError::NotLegacyAccount
+        );
+    }
+
+    grow_account(
+        &whitelist_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        SimpleWhitelist::SPACE,
+    )?;
+
+    msg!("Whitelist migrated: {}", whitelist_info.key());
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct RedeemLegacyTokens<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.is_paused(PauseFlags::UNWRAP) @ BridgeError::UnwrapPaused
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    pub restricted_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = restricted_token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_2022_program
+    )]
+    pub holder_restricted_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", restricted_token_mint.key().as_ref()],
+        bump = token_vault.bump,
+        constraint = token_vault.restricted_token_mint == restricted_token_mint.key() @ BridgeError::InvalidTokenVault,
+        constraint = !token_vault.is_paused(PauseFlags::UNWRAP) @ BridgeError::UnwrapPaused,
+        constraint = token_vault.legacy_locked >= amount @ BridgeError::InsufficientLockedTokens
+    )]
+    pub token_vault: Box<Account<'info, TokenVault>>,
+
+    /// Only exists once the migrated vault has been registered again
+    #[account(
+        seeds = [b"hook_metadata", token_vault.key().as_ref()],
+        bump = hook_metadata.bump
+    )]
+    pub hook_metadata: Box<Account<'info, HookMetadata>>,
+
+    #[account(
+        mut,
+        address = token_vault.vault_token_account @ BridgeError::InvalidTokenVault
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bridge_config.legacy_bridge_token_mint == Some(legacy_bridge_token_mint.key()) @ BridgeError::BridgeTokenMintMismatch,
+        mint::token_program = token_program
+    )]
+    pub legacy_bridge_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = legacy_bridge_token_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+        constraint = holder_legacy_token_account.amount >= amount @ BridgeError::InvalidBridgeTokenAmount
+    )]
+    pub holder_legacy_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"whitelist", restricted_token_mint.key().as_ref()],
+        bump = whitelist.bump,
+        constraint = whitelist.mint == restricted_token_mint.key() @ BridgeError::InvalidWhitelistAccount
+    )]
+    pub whitelist: Option<Account<'info, SimpleWhitelist>>,
+
+    /// CHECK: KYC attestation PDA, validated when the hook type is KYC
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
+
+    /// Program of the legacy bridge mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burns tokens of the shared bridge mint of a migrated deployment and
+/// releases as many restricted tokens from a migrated vault to the holder,
+/// 1:1 in base units as the legacy unwrap did. Legacy tokens paid no bridge
+/// fee on the way in, so none is charged here, and user quotas are not
+/// counted; the vault's pause flags, unwrap rate limit and hook checks apply
+/// as they do to `unwrap_tokens`.
+pub fn redeem_legacy_tokens<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemLegacyTokens<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, BridgeError::InvalidBridgeTokenAmount);
+
+    let accounts = &mut *ctx.accounts;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        accounts.token_vault.unwrap_rate_limit.consume(amount, now),
+        BridgeError::RateLimitExceeded
+    );
+
+    let (_, current_hook_program_id) = detect_mint_extensions(
+        &accounts.restricted_token_mint.to_account_info(),
+    )?;
+    require!(
+        current_hook_program_id == accounts.token_vault.hook_program_id,
+        BridgeError::UnapprovedHookProgram
+    );
+
+    if let Some(hook_program_id) = accounts.token_vault.hook_program_id {
+        require!(
+            accounts.bridge_config.approved_hook_programs.contains(&hook_program_id),
+            BridgeError::UnapprovedHookProgram
+        );
+    }
+
+    let holder = accounts.holder.key();
+
+    validate_hook_requirements(
+        &accounts.hook_metadata,
+        &accounts.restricted_token_mint.key(),
+        &holder,
+        accounts.kyc_attestation.as_ref().map(|info| info.as_ref()),
+    )?;
+
+    if accounts.hook_metadata.hook_type == HookType::Whitelist {
+        let whitelist = accounts.whitelist.as_ref()
+            .ok_or(BridgeError::WhitelistAccountRequired)?;
+        require!(
+            whitelist.is_whitelisted(&holder),
+            BridgeError::RecipientNotWhitelisted
+        );
+    }
+
+    burn(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Burn {
+                mint: accounts.legacy_bridge_token_mint.to_account_info(),
+                from: accounts.holder_legacy_token_account.to_account_info(),
+                authority: accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    msg!("Burned {} legacy bridge tokens from holder", amount);
+
+    let fee = calculate_transfer_fee(
+        &accounts.restricted_token_mint.to_account_info(),
+        amount,
+    )?;
+    let expected_received = amount
+        .checked_sub(fee)
+        .ok_or(BridgeError::TransferFeeCalculationFailed)?;
+    require!(expected_received > 0, BridgeError::InvalidBridgeTokenAmount);
+    let holder_balance_before = accounts.holder_restricted_token_account.amount;
+
+    let restricted_mint_key = accounts.restricted_token_mint.key();
+    let signer_seeds: &[&[u8]] = &[
+        b"token_vault",
+        restricted_mint_key.as_ref(),
+        &[accounts.token_vault.bump],
+    ];
+
+    invoke_transfer_checked(
+        accounts.token_2022_program.key,
+        accounts.vault_token_account.to_account_info(),
+        accounts.restricted_token_mint.to_account_info(),
+        accounts.holder_restricted_token_account.to_account_info(),
+        accounts.token_vault.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        accounts.restricted_token_mint.decimals,
+        &[signer_seeds],
+    ).map_err(transfer_hook_error)?;
+
+    accounts.holder_restricted_token_account.reload()?;
+    let received = accounts.holder_restricted_token_account.amount
+        .checked_sub(holder_balance_before)
+        .ok_or(BridgeError::MathOverflow)?;
+    require!(
+        received == expected_received,
+        BridgeError::TransferFeeCalculationFailed
+    );
+
+    let token_vault = &mut accounts.token_vault;
+    token_vault.legacy_locked = token_vault.legacy_locked
+        .checked_sub(amount)
+        .ok_or(BridgeError::MathOverflow)?;
+    token_vault.total_locked = token_vault.total_locked
+        .checked_sub(amount)
+        .ok_or(BridgeError::MathOverflow)?;
+
+    let bridge_config = &mut accounts.bridge_config;
+    bridge_config.total_locked_amount = bridge_config.total_locked_amount
+        .checked_sub(amount)
+        .ok_or(BridgeError::MathOverflow)?;
+
+    msg!("Redeemed {} legacy bridge tokens, {} legacy locked left", amount, token_vault.legacy_locked);
+
+    let redeemed = LegacyTokensRedeemed {
+        holder,
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        legacy_bridge_token_mint: accounts.legacy_bridge_token_mint.key(),
+        burned: amount,
+        transfer_fee: fee,
+        received,
+        legacy_locked: token_vault.legacy_locked,
+        vault_total_locked: token_vault.total_locked,
+        bridge_total_locked: bridge_config.total_locked_amount,
+    };
+
+    emit_cpi!(redeemed);
+
+    Ok(())
+}
@@ -1,13 +1,27 @@
 pub mod initialize_bridge;
-pub mod create_bridge_token_mint;
+pub mod register_vault;
 pub mod wrap_tokens;
 pub mod unwrap_tokens;
 pub mod admin;
+pub mod roles;
+pub mod multisig;
+pub mod reserves;
+pub mod surplus;
+pub mod fees;
+pub mod quotas;
 pub mod whitelist_hook;
+pub mod migrate;
 
 pub use initialize_bridge::*;
-pub use create_bridge_token_mint::*;
+pub use register_vault::*;
 pub use wrap_tokens::*;
 pub use unwrap_tokens::*;
 pub use admin::*;
-pub use whitelist_hook::*;
\ No newline at end of file
+pub use roles::*;
+pub use multisig::*;
+pub use reserves::*;
+pub use surplus::*;
+pub use fees::*;
+pub use quotas::*;
+pub use whitelist_hook::*;
+pub use migrate::*;
\ No newline at end of file
@@ -0,0 +1,786 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::instructions::admin::{
+    apply_timelock_config, approve_hook_program, clear_pending_authority, set_pending_authority,
+    unapprove_hook_program,
+};
+use crate::instructions::fees::{apply_fee_config, apply_vault_fees};
+use crate::instructions::roles::{apply_grant_role, apply_revoke_role};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAdminMultisig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = authority @ BridgeError::Unauthorized,
+        constraint = bridge_config.multisig.is_none() @ BridgeError::InvalidMultisigConfig
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Kept after `AdminAction::RemoveMultisig`, so a later multisig
+    /// continues its proposal numbering.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AdminMultisig::SPACE,
+        seeds = [b"admin_multisig"],
+        bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateAdminProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump,
+        constraint = admin_multisig.is_signer(&proposer.key()) @ BridgeError::NotMultisigSigner
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::SPACE,
+        seeds = [
+            b"admin_proposal",
+            admin_multisig.key().as_ref(),
+            admin_multisig.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump,
+        constraint = admin_multisig.is_signer(&approver.key()) @ BridgeError::NotMultisigSigner
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal",
+            admin_multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ BridgeError::ProposalAlreadyExecuted,
+        constraint = !proposal.cancelled @ BridgeError::ProposalCancelled,
+        constraint = proposal.is_current(&admin_multisig, &bridge_config) @ BridgeError::ProposalStale
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteAdminProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.multisig == Some(admin_multisig.key()) @ BridgeError::InvalidMultisigConfig
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal",
+            admin_multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ BridgeError::ProposalAlreadyExecuted,
+        constraint = !proposal.cancelled @ BridgeError::ProposalCancelled,
+        constraint = proposal.is_current(&admin_multisig, &bridge_config) @ BridgeError::ProposalStale
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    /// Target of vault-policy actions
+    #[account(mut)]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+
+    /// Target of `AdminAction::UpdateHookMetadata`
+    #[account(mut)]
+    pub hook_metadata: Option<Account<'info, HookMetadata>>,
+
+    /// Target of `AdminAction::GrantRole` and `AdminAction::RevokeRole`
+    #[account(mut)]
+    pub bridge_roles: Option<Account<'info, BridgeRoles>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal",
+            admin_multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref()
+        ],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ BridgeError::ProposalAlreadyExecuted,
+        constraint = !proposal.cancelled @ BridgeError::ProposalCancelled
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+pub fn initialize_admin_multisig(
+    ctx: Context<InitializeAdminMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_multisig_config(&signers, threshold)?;
+
+    let admin_multisig = &mut ctx.accounts.admin_multisig;
+    if admin_multisig.bridge_config != Pubkey::default() {
+        // Reinstalled after a removal: proposals left from the previous
+        // signer set must stay stale.
+        admin_multisig.signer_set_version = admin_multisig.signer_set_version
+            .checked_add(1)
+            .ok_or(BridgeError::MathOverflow)?;
+    }
+    admin_multisig.bridge_config = ctx.accounts.bridge_config.key();
+    admin_multisig.signers = signers;
+    admin_multisig.threshold = threshold;
+    admin_multisig.bump = ctx.bumps.admin_multisig;
+
+    ctx.accounts.bridge_config.multisig = Some(admin_multisig.key());
+
+    msg!("Admin multisig initialized: {}", admin_multisig.key());
+    msg!("Threshold: {} of {}", threshold, admin_multisig.signers.len());
+
+    emit_cpi!(MultisigUpdated {
+        multisig: admin_multisig.key(),
+        signers: admin_multisig.signers.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+pub fn create_admin_proposal(
+    ctx: Context<CreateAdminProposal>,
+    action: AdminAction,
+) -> Result<()> {
+    match &action {
+        AdminAction::UpdateMultisig { signers, threshold } => {
+            validate_multisig_config(signers, *threshold)?;
+        }
+        AdminAction::SetTimelockConfig { delay, exemptions } => {
+            // Validate against a copy so bad parameters fail at proposal time.
+            let mut bridge_config = ctx.accounts.bridge_config.clone().into_inner();
+            apply_timelock_config(&mut bridge_config, *delay, *exemptions)?;
+        }
+        _ => {}
+    }
+
+    let admin_multisig = &mut ctx.accounts.admin_multisig;
+    let proposer = ctx.accounts.proposer.key();
+
+    // The proposer counts as the first approval.
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = admin_multisig.key();
+    proposal.index = admin_multisig.proposal_count;
+    proposal.proposer = proposer;
+    proposal.action = action.clone();
+    proposal.approvals = vec![proposer];
+    proposal.eta = None;
+    proposal.executed = false;
+    proposal.cancelled = false;
+    proposal.signer_set_version = admin_multisig.signer_set_version;
+    proposal.timelock_delay = ctx.accounts.bridge_config.timelock_delay;
+    proposal.bump = ctx.bumps.proposal;
+    let queued_eta = queue_if_ready(proposal, admin_multisig, &ctx.accounts.bridge_config)?;
+
+    admin_multisig.proposal_count = admin_multisig
+        .proposal_count
+        .checked_add(1)
+        .ok_or(BridgeError::MathOverflow)?;
+
+    msg!("Admin proposal {} created by {}", proposal.index, proposer);
+
+    emit_cpi!(AdminProposalCreated {
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer,
+        action,
+    });
+
+    if let Some(eta) = queued_eta {
+        emit_cpi!(AdminProposalQueued {
+            proposal: proposal.key(),
+            index: proposal.index,
+            eta,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let admin_multisig = &ctx.accounts.admin_multisig;
+    let approver = ctx.accounts.approver.key();
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        !proposal.approvals.contains(&approver),
+        BridgeError::AlreadyApproved
+    );
+
+    proposal.approvals.push(approver);
+    let approvals = proposal.approval_count(admin_multisig);
+    let queued_eta = queue_if_ready(proposal, admin_multisig, &ctx.accounts.bridge_config)?;
+
+    msg!("Admin proposal {} approved by {}", proposal.index, approver);
+    msg!("Approvals: {} of {}", approvals, admin_multisig.threshold);
+
+    emit_cpi!(AdminProposalApproved {
+        proposal: proposal.key(),
+        index: proposal.index,
+        approver,
+        approvals: approvals as u8,
+        threshold: admin_multisig.threshold,
+    });
+
+    if let Some(eta) = queued_eta {
+        emit_cpi!(AdminProposalQueued {
+            proposal: proposal.key(),
+            index: proposal.index,
+            eta,
+        });
+    }
+
+    Ok(())
+}
+
+/// Applies a proposal's action once its threshold was reached and its
+/// timelock `eta` has passed. Anyone can submit the execution. Proposals
+/// created before the last `UpdateMultisig` or timelock change are stale
+/// and can no longer be approved or executed.
+pub fn execute_admin_proposal(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+    let eta = ctx.accounts.proposal.eta.ok_or(BridgeError::ThresholdNotReached)?;
+    require!(
+        Clock::get()?.unix_timestamp >= eta,
+        BridgeError::TimelockNotElapsed
+    );
+
+    let multisig_key = ctx.accounts.admin_multisig.key();
+    let action = ctx.accounts.proposal.action.clone();
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    match &action {
+        AdminAction::AddApprovedHookProgram { hook_program_id } => {
+            approve_hook_program(bridge_config, *hook_program_id)?;
+
+            msg!("Added approved hook program: {}", hook_program_id);
+
+            emit_cpi!(HookApproved {
+                hook_program_id: *hook_program_id,
+                approved_count: bridge_config.approved_hook_programs.len() as u8,
+            });
+        }
+        AdminAction::RemoveApprovedHookProgram { hook_program_id } => {
+            if unapprove_hook_program(bridge_config, *hook_program_id) {
+                msg!("Removed approved hook program: {}", hook_program_id);
+
+                emit_cpi!(HookRemoved {
+                    hook_program_id: *hook_program_id,
+                    approved_count: bridge_config.approved_hook_programs.len() as u8,
+                });
+            }
+        }
+        AdminAction::ProposeAuthority { new_authority } => {
+            set_pending_authority(bridge_config, *new_authority)?;
+
+            msg!("Bridge authority transfer proposed to: {}", new_authority);
+
+            emit_cpi!(AuthorityTransferProposed {
+                authority: bridge_config.authority,
+                pending_authority: *new_authority,
+            });
+        }
+        AdminAction::CancelAuthorityTransfer => {
+            let cancelled_authority = clear_pending_authority(bridge_config)?;
+
+            msg!("Bridge authority transfer to {} cancelled", cancelled_authority);
+
+            emit_cpi!(AuthorityTransferCancelled {
+                authority: bridge_config.authority,
+                cancelled_authority,
+            });
+        }
+        AdminAction::Unpause { pause_flags } => {
+            require!(
+                *pause_flags != 0 && pause_flags & !PauseFlags::ALL == 0,
+                BridgeError::InvalidPauseFlags
+            );
+
+            let previous_flags = bridge_config.pause_flags;
+            if bridge_config.unpause(*pause_flags) {
+                msg!("Bridge pause flags changed from {:#b} to {:#b}", previous_flags, bridge_config.pause_flags);
+
+                emit_cpi!(PauseStateChanged {
+                    authority: multisig_key,
+                    previous_flags,
+                    pause_flags: bridge_config.pause_flags,
+                    pause_reason: bridge_config.pause_reason,
+                });
+            }
+        }
+        AdminAction::SetAdmissionOverrides { admission_overrides } => {
+            require!(
+                admission_overrides & !AdmissionOverrides::ALL == 0,
+                BridgeError::InvalidExtensionConfig
+            );
+            bridge_config.admission_overrides = *admission_overrides;
+
+            msg!("Mint admission overrides set to: {:#b}", admission_overrides);
+
+            emit_cpi!(AdmissionOverridesUpdated {
+                authority: multisig_key,
+                admission_overrides: *admission_overrides,
+            });
+        }
+        AdminAction::SetPermissionlessVaultRegistration { enabled } => {
+            bridge_config.permissionless_vault_registration = *enabled;
+
+            msg!("Permissionless vault registration set to: {}", enabled);
+
+            emit_cpi!(VaultRegistrationModeUpdated {
+                authority: multisig_key,
+                permissionless: *enabled,
+            });
+        }
+        AdminAction::UpdateMultisig { signers, threshold } => {
+            validate_multisig_config(signers, *threshold)?;
+
+            let admin_multisig = &mut ctx.accounts.admin_multisig;
+            admin_multisig.signers = signers.clone();
+            admin_multisig.threshold = *threshold;
+            admin_multisig.signer_set_version = admin_multisig.signer_set_version
+                .checked_add(1)
+                .ok_or(BridgeError::MathOverflow)?;
+
+            msg!("Admin multisig updated: {} of {}", threshold, signers.len());
+
+            emit_cpi!(MultisigUpdated {
+                multisig: multisig_key,
+                signers: signers.clone(),
+                threshold: *threshold,
+            });
+        }
+        AdminAction::RemoveMultisig => {
+            // A delay cannot outlive the multisig that has to approve its removal.
+            require!(bridge_config.timelock_delay == 0, BridgeError::TimelockActive);
+            bridge_config.multisig = None;
+
+            msg!("Admin multisig removed: {}", multisig_key);
+
+            emit_cpi!(MultisigRemoved {
+                multisig: multisig_key,
+            });
+        }
+        AdminAction::SetTimelockConfig { delay, exemptions } => {
+            apply_timelock_config(bridge_config, *delay, *exemptions)?;
+
+            msg!("Timelock delay set to {}s, exemptions: {:#b}", delay, exemptions);
+
+            emit_cpi!(TimelockConfigUpdated {
+                authority: multisig_key,
+                delay: *delay,
+                exemptions: *exemptions,
+            });
+        }
+        AdminAction::SetVaultEnabled { token_vault, enabled } => {
+            let vault = ctx.accounts.token_vault
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(vault.key(), *token_vault, BridgeError::ProposalTargetMismatch);
+
+            vault.is_enabled = *enabled;
+
+            msg!("Vault for mint {} enabled: {}", vault.restricted_token_mint, enabled);
+
+            emit_cpi!(VaultStatusChanged {
+                token_vault: *token_vault,
+                restricted_token_mint: vault.restricted_token_mint,
+                is_enabled: *enabled,
+            });
+        }
+        AdminAction::UnpauseVault { token_vault, pause_flags } => {
+            require!(
+                *pause_flags != 0 && pause_flags & !PauseFlags::VAULT == 0,
+                BridgeError::InvalidPauseFlags
+            );
+
+            let vault = ctx.accounts.token_vault
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(vault.key(), *token_vault, BridgeError::ProposalTargetMismatch);
+
+            let previous_flags = vault.pause_flags;
+            if vault.unpause(*pause_flags) {
+                msg!("Vault for mint {} pause flags: {:#b}", vault.restricted_token_mint, vault.pause_flags);
+
+                emit_cpi!(VaultPauseStateChanged {
+                    authority: multisig_key,
+                    token_vault: *token_vault,
+                    restricted_token_mint: vault.restricted_token_mint,
+                    previous_flags,
+                    pause_flags: vault.pause_flags,
+                    pause_reason: vault.pause_reason,
+                });
+            }
+        }
+        AdminAction::SetTreasury { treasury } => {
+            bridge_config.treasury = Some(*treasury);
+
+            msg!("Treasury set to: {}", treasury);
+
+            emit_cpi!(TreasuryUpdated {
+                authority: multisig_key,
+                treasury: *treasury,
+            });
+        }
+        AdminAction::SetFeeConfig { default_wrap_fee_bps, default_unwrap_fee_bps, max_fee_bps } => {
+            apply_fee_config(
+                bridge_config,
+                *default_wrap_fee_bps,
+                *default_unwrap_fee_bps,
+                *max_fee_bps,
+            )?;
+
+            msg!("Default fees: wrap {} bps, unwrap {} bps", default_wrap_fee_bps, default_unwrap_fee_bps);
+
+            emit_cpi!(FeeConfigUpdated {
+                authority: multisig_key,
+                default_wrap_fee_bps: *default_wrap_fee_bps,
+                default_unwrap_fee_bps: *default_unwrap_fee_bps,
+                max_fee_bps: *max_fee_bps,
+            });
+        }
+        AdminAction::SetVaultFees { token_vault, wrap_fee_bps, unwrap_fee_bps } => {
+            let vault = ctx.accounts.token_vault
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(vault.key(), *token_vault, BridgeError::ProposalTargetMismatch);
+
+            apply_vault_fees(bridge_config, vault, *wrap_fee_bps, *unwrap_fee_bps)?;
+
+            msg!("Vault for mint {} fees: wrap {:?}, unwrap {:?}", vault.restricted_token_mint, wrap_fee_bps, unwrap_fee_bps);
+
+            emit_cpi!(VaultFeesUpdated {
+                authority: multisig_key,
+                token_vault: *token_vault,
+                wrap_fee_bps: *wrap_fee_bps,
+                unwrap_fee_bps: *unwrap_fee_bps,
+            });
+        }
+        AdminAction::SetVaultLimits { token_vault, limits } => {
+            require!(limits.is_valid(), BridgeError::InvalidVaultLimits);
+
+            let vault = ctx.accounts.token_vault
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(vault.key(), *token_vault, BridgeError::ProposalTargetMismatch);
+
+            vault.limits = *limits;
+
+            msg!("Vault for mint {} limits: {:?}", vault.restricted_token_mint, limits);
+
+            emit_cpi!(VaultLimitsUpdated {
+                authority: multisig_key,
+                token_vault: *token_vault,
+                limits: *limits,
+            });
+        }
+        AdminAction::SetVaultRateLimits { token_vault, wrap, unwrap } => {
+            let vault = ctx.accounts.token_vault
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(vault.key(), *token_vault, BridgeError::ProposalTargetMismatch);
+
+            let now = Clock::get()?.unix_timestamp;
+            vault.wrap_rate_limit = RateLimit::new(*wrap, now);
+            vault.unwrap_rate_limit = RateLimit::new(*unwrap, now);
+
+            msg!("Vault for mint {} rate limits: wrap {:?}, unwrap {:?}", vault.restricted_token_mint, wrap, unwrap);
+
+            emit_cpi!(VaultRateLimitsUpdated {
+                authority: multisig_key,
+                token_vault: *token_vault,
+                wrap: *wrap,
+                unwrap: *unwrap,
+            });
+        }
+        AdminAction::UpdateHookMetadata { token_vault, hook_type, is_active } => {
+            let hook_metadata = ctx.accounts.hook_metadata
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(hook_metadata.vault, *token_vault, BridgeError::ProposalTargetMismatch);
+
+            hook_metadata.hook_type = *hook_type;
+            hook_metadata.is_active = *is_active;
+
+            msg!("Hook metadata updated for vault: {}", token_vault);
+
+            emit_cpi!(HookMetadataUpdated {
+                token_vault: *token_vault,
+                hook_metadata: hook_metadata.key(),
+                hook_type: *hook_type,
+                is_active: *is_active,
+            });
+        }
+        AdminAction::GrantRole { member, roles } => {
+            let bridge_roles = ctx.accounts.bridge_roles
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(
+                bridge_roles.bridge_config,
+                bridge_config.key(),
+                BridgeError::ProposalTargetMismatch
+            );
+
+            let held = apply_grant_role(bridge_roles, *member, *roles)?;
+
+            msg!("Granted roles {:#b} to {}", roles, member);
+
+            emit_cpi!(RoleGranted {
+                authority: multisig_key,
+                member: *member,
+                granted: *roles,
+                roles: held,
+            });
+        }
+        AdminAction::RevokeRole { member, roles } => {
+            let bridge_roles = ctx.accounts.bridge_roles
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(
+                bridge_roles.bridge_config,
+                bridge_config.key(),
+                BridgeError::ProposalTargetMismatch
+            );
+
+            if let Some(held) = apply_revoke_role(bridge_roles, *member, *roles)? {
+                msg!("Revoked roles {:#b} from {}", roles, member);
+
+                emit_cpi!(RoleRevoked {
+                    authority: multisig_key,
+                    member: *member,
+                    revoked: *roles,
+                    roles: held,
+                });
+            }
+        }
+        AdminAction::SetVaultUserQuotas { token_vault, user_quotas } => {
+            let vault = ctx.accounts.token_vault
+                .as_mut()
+                .ok_or(BridgeError::ProposalTargetMismatch)?;
+            require_keys_eq!(vault.key(), *token_vault, BridgeError::ProposalTargetMismatch);
+
+            vault.user_quotas = *user_quotas;
+
+            msg!("Vault for mint {} user quotas: {:?}", vault.restricted_token_mint, user_quotas);
+
+            emit_cpi!(VaultUserQuotasUpdated {
+                authority: multisig_key,
+                token_vault: *token_vault,
+                user_quotas: *user_quotas,
+            });
+        }
+        AdminAction::ReconcileVaultSurplus { .. } | AdminAction::SetUserQuota { .. } => {
+            return err!(BridgeError::ProposalNotExecutable);
+        }
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+
+    msg!("Admin proposal {} executed", proposal.index);
+
+    emit_cpi!(AdminProposalExecuted {
+        proposal: proposal.key(),
+        index: proposal.index,
+        executor: ctx.accounts.executor.key(),
+        action,
+    });
+
+    Ok(())
+}
+
+/// Marks an approved proposal executed for an instruction that carries out
+/// the action itself because it needs accounts `execute_admin_proposal`
+/// does not take. Applies the same checks as `execute_admin_proposal`.
+pub(crate) fn consume_proposal(
+    bridge_config: &BridgeConfig,
+    admin_multisig: &Account<AdminMultisig>,
+    proposal: &mut Account<AdminProposal>,
+    action: &AdminAction,
+) -> Result<()> {
+    require!(
+        bridge_config.multisig == Some(admin_multisig.key()),
+        BridgeError::InvalidMultisigConfig
+    );
+    require_keys_eq!(proposal.multisig, admin_multisig.key(), BridgeError::InvalidMultisigConfig);
+    require!(!proposal.executed, BridgeError::ProposalAlreadyExecuted);
+    require!(!proposal.cancelled, BridgeError::ProposalCancelled);
+    require!(proposal.is_current(admin_multisig, bridge_config), BridgeError::ProposalStale);
+    require!(proposal.action == *action, BridgeError::ProposalTargetMismatch);
+
+    let eta = proposal.eta.ok_or(BridgeError::ThresholdNotReached)?;
+    require!(
+        Clock::get()?.unix_timestamp >= eta,
+        BridgeError::TimelockNotElapsed
+    );
+
+    proposal.executed = true;
+
+    Ok(())
+}
+
+/// Guardian veto for a pending proposal, typically used while it sits in the
+/// timelock queue. Open to explicitly granted guardians and multisig
+/// signers; the bridge authority's implicit roles do not count, so the
+/// authority alone cannot veto the multisig.
+pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+    require!(
+        ctx.accounts.bridge_roles.granted_roles(&guardian) & Roles::GUARDIAN != 0
+            || ctx.accounts.admin_multisig.is_signer(&guardian),
+        BridgeError::MissingRole
+    );
+    require!(
+        ctx.accounts.proposal.action.is_cancellable(),
+        BridgeError::ProposalNotCancellable
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.cancelled = true;
+
+    msg!("Admin proposal {} cancelled by {}", proposal.index, ctx.accounts.guardian.key());
+
+    emit_cpi!(AdminProposalCancelled {
+        proposal: proposal.key(),
+        index: proposal.index,
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
+
+/// Starts the timelock once a proposal first reaches its threshold and
+/// returns the `eta` it was queued with.
+fn queue_if_ready(
+    proposal: &mut AdminProposal,
+    admin_multisig: &AdminMultisig,
+    bridge_config: &BridgeConfig,
+) -> Result<Option<i64>> {
+    if proposal.eta.is_some()
+        || proposal.approval_count(admin_multisig) < admin_multisig.threshold as usize
+    {
+        return Ok(None);
+    }
+
+    let delay = if proposal.action.timelock_applies(bridge_config) {
+        bridge_config.timelock_delay
+    } else {
+        0
+    };
+    let eta = Clock::get()?
+        .unix_timestamp
+        .checked_add(delay)
+        .ok_or(BridgeError::MathOverflow)?;
+    proposal.eta = Some(eta);
+
+    msg!("Admin proposal {} executable at {}", proposal.index, eta);
+
+    Ok(Some(eta))
+}
+
+fn validate_multisig_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= AdminMultisig::MAX_SIGNERS,
+        BridgeError::InvalidMultisigConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        BridgeError::InvalidMultisigConfig
+    );
+
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[i + 1..].contains(signer),
+            BridgeError::InvalidMultisigConfig
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::require_role;
+use super::admin::{require_no_timelock, UpdateTokenVault};
+use super::multisig::consume_proposal;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct ManageUserQuota<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        seeds = [b"token_vault", token_vault.restricted_token_mint.as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = UserQuota::SPACE,
+        seeds = [b"user_quota", token_vault.key().as_ref(), user.as_ref()],
+        bump
+    )]
+    pub user_quota: Account<'info, UserQuota>,
+
+    /// Required together with `proposal` while vault policy is timelocked
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    /// Approved `AdminAction::SetUserQuota` proposal, consumed here
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Default daily quotas for every user of the vault.
+pub fn set_vault_user_quotas(
+    ctx: Context<UpdateTokenVault>,
+    user_quotas: UserQuotaConfig,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::VAULT_MANAGER,
+    )?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::VAULT_POLICY)?;
+
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.user_quotas = user_quotas;
+
+    msg!("Vault for mint {} user quotas: {:?}", token_vault.restricted_token_mint, user_quotas);
+
+    emit_cpi!(VaultUserQuotasUpdated {
+        authority: ctx.accounts.admin.key(),
+        token_vault: token_vault.key(),
+        user_quotas,
+    });
+
+    Ok(())
+}
+
+/// Per-user quota overrides; `None` falls back to the vault default.
+///
+/// Vault policy like the rest. The quota account may not exist yet, so a
+/// timelocked override is carried out here against an approved
+/// `AdminAction::SetUserQuota` proposal rather than by
+/// `execute_admin_proposal`.
+pub fn set_user_quota(
+    ctx: Context<ManageUserQuota>,
+    user: Pubkey,
+    wrap_quota: Option<u64>,
+    unwrap_quota: Option<u64>,
+) -> Result<()> {
+    if ctx.accounts.bridge_config.timelock_applies(GovernanceActions::VAULT_POLICY) {
+        let action = AdminAction::SetUserQuota {
+            token_vault: ctx.accounts.token_vault.key(),
+            user,
+            wrap_quota,
+            unwrap_quota,
+        };
+        let admin_multisig = ctx.accounts.admin_multisig.as_ref()
+            .ok_or(BridgeError::TimelockRequired)?;
+        let proposal = ctx.accounts.proposal.as_mut()
+            .ok_or(BridgeError::TimelockRequired)?;
+        consume_proposal(&ctx.accounts.bridge_config, admin_multisig, proposal, &action)?;
+
+        let proposal_key = proposal.key();
+        let index = proposal.index;
+        msg!("Admin proposal {} executed", index);
+
+        emit_cpi!(AdminProposalExecuted {
+            proposal: proposal_key,
+            index,
+            executor: ctx.accounts.admin.key(),
+            action,
+        });
+    } else {
+        require_role(
+            &ctx.accounts.bridge_config,
+            &ctx.accounts.bridge_roles,
+            &ctx.accounts.admin.key(),
+            Roles::VAULT_MANAGER,
+        )?;
+    }
+
+    let user_quota = &mut ctx.accounts.user_quota;
+    user_quota.init_if_new(ctx.accounts.token_vault.key(), user, ctx.bumps.user_quota);
+    user_quota.wrap_quota = wrap_quota;
+    user_quota.unwrap_quota = unwrap_quota;
+
+    msg!("User {} quotas: wrap {:?}, unwrap {:?}", user, wrap_quota, unwrap_quota);
+
+    emit_cpi!(UserQuotaUpdated {
+        authority: ctx.accounts.admin.key(),
+        token_vault: ctx.accounts.token_vault.key(),
+        user,
+        wrap_quota,
+        unwrap_quota,
+    });
+
+    Ok(())
+}
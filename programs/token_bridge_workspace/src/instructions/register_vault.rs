@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3, Metadata},
+    token::Token,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::{bridge_token_metadata, check_mint_admission, detect_mint_extensions};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterVault<'info> {
+    #[account(mut)]
+    pub registrar: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.permissionless_vault_registration
+            || bridge_roles.has_role(&bridge_config, &registrar.key(), Roles::VAULT_MANAGER) @ BridgeError::MissingRole,
+        constraint = !bridge_config.is_paused(PauseFlags::REGISTRATION) @ BridgeError::VaultRegistrationPaused
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mint::token_program = token_2022_program
+    )]
+    pub restricted_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = registrar,
+        space = TokenVault::SPACE,
+        seeds = [b"token_vault", restricted_token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenVault>>,
+
+    #[account(
+        init_if_needed,
+        payer = registrar,
+        associated_token::mint = restricted_token_mint,
+        associated_token::authority = token_vault,
+        associated_token::token_program = token_2022_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = registrar,
+        seeds = [b"bridge_token_mint", restricted_token_mint.key().as_ref()],
+        bump,
+        mint::decimals = restricted_token_mint.decimals,
+        mint::authority = bridge_config,
+        mint::token_program = token_program
+    )]
+    pub bridge_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = registrar,
+        space = HookMetadata::SPACE,
+        seeds = [b"hook_metadata", token_vault.key().as_ref()],
+        bump
+    )]
+    pub hook_metadata: Box<Account<'info, HookMetadata>>,
+
+    #[account(
+        init_if_needed,
+        payer = registrar,
+        seeds = [b"fee_account", token_vault.key().as_ref()],
+        bump,
+        token::mint = bridge_token_mint,
+        token::authority = bridge_config,
+        token::token_program = token_program
+    )]
+    pub fee_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata PDA of the bridge mint, created by the CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), bridge_token_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub bridge_token_metadata: UncheckedAccount<'info>,
+
+    /// Bridge mints are classic SPL Token mints
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn register_vault(ctx: Context<RegisterVault>, hook_type: HookType) -> Result<()> {
+    // The vault accounts are `init_if_needed` so that registering a mint twice
+    // fails here with a clear error instead of a generic "already in use".
+    // A bridge mint is only ever created together with its vault, so it can
+    // never be swapped out from under outstanding bridge tokens.
+    require!(
+        ctx.accounts.token_vault.bridge_config == Pubkey::default()
+            && ctx.accounts.bridge_token_mint.supply == 0,
+        BridgeError::TokenVaultAlreadyExists
+    );
+
+    let restricted_mint_info = ctx.accounts.restricted_token_mint.to_account_info();
+    let restricted_mint_key = restricted_mint_info.key();
+
+    let (extensions_bitmap, hook_program_id) = detect_mint_extensions(&restricted_mint_info)?;
+    check_mint_admission(
+        &restricted_mint_info,
+        extensions_bitmap,
+        ctx.accounts.bridge_config.admission_overrides,
+    )?;
+
+    if let Some(hook_program_id) = hook_program_id {
+        // Token-2022 would call back into the bridge from inside its own
+        // transfer CPI, and Solana rejects that reentrancy, so such a vault
+        // could never wrap or unwrap.
+        require_keys_neq!(hook_program_id, crate::ID, BridgeError::BridgeHookNotSupported);
+        require!(
+            ctx.accounts.bridge_config.approved_hook_programs.contains(&hook_program_id),
+            BridgeError::UnapprovedHookProgram
+        );
+    }
+
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.bridge_config = ctx.accounts.bridge_config.key();
+    token_vault.restricted_token_mint = restricted_mint_key;
+    token_vault.vault_token_account = ctx.accounts.vault_token_account.key();
+    token_vault.bridge_token_mint = ctx.accounts.bridge_token_mint.key();
+    // Non-zero only for a vault carried over by `migrate_legacy_vault`
+    token_vault.total_locked = token_vault.legacy_locked;
+    token_vault.hook_program_id = hook_program_id;
+    token_vault.extensions_bitmap = extensions_bitmap;
+    token_vault.bump = ctx.bumps.token_vault;
+    token_vault.is_enabled = true;
+    token_vault.pause_flags = 0;
+    token_vault.pause_reason = 0;
+    token_vault.wrap_fee_bps = None;
+    token_vault.unwrap_fee_bps = None;
+    token_vault.limits = VaultLimits::default();
+    token_vault.wrap_rate_limit = RateLimit::default();
+    token_vault.unwrap_rate_limit = RateLimit::default();
+    token_vault.user_quotas = UserQuotaConfig::default();
+
+    let hook_metadata = &mut ctx.accounts.hook_metadata;
+    hook_metadata.vault = token_vault.key();
+    hook_metadata.hook_program_id = hook_program_id.unwrap_or_default();
+    hook_metadata.extra_account_meta_list = hook_program_id
+        .map(|program_id| get_extra_account_metas_address(&restricted_mint_key, &program_id))
+        .unwrap_or_default();
+    hook_metadata.hook_type = hook_type;
+    hook_metadata.is_active = true;
+    hook_metadata.bump = ctx.bumps.hook_metadata;
+
+    let metadata = bridge_token_metadata(&restricted_mint_info)?;
+    let bridge_config_seeds: &[&[u8]] = &[b"bridge_config", &[ctx.accounts.bridge_config.bump]];
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.bridge_token_metadata.to_account_info(),
+                mint: ctx.accounts.bridge_token_mint.to_account_info(),
+                mint_authority: ctx.accounts.bridge_config.to_account_info(),
+                payer: ctx.accounts.registrar.to_account_info(),
+                update_authority: ctx.accounts.bridge_config.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            &[bridge_config_seeds],
+        ),
+        metadata.clone(),
+        true,
+        true,
+        None,
+    )?;
+
+    msg!("Token vault registered for mint: {}", restricted_mint_key);
+    msg!("Bridge token mint: {}", token_vault.bridge_token_mint);
+    msg!("Detected extensions bitmap: {:#b}", extensions_bitmap);
+    if let Some(hook_program_id) = hook_program_id {
+        msg!("Transfer hook program: {}", hook_program_id);
+    }
+
+    msg!("Bridge token metadata: {} ({})", metadata.name, metadata.symbol);
+
+    emit_cpi!(VaultRegistered {
+        registrar: ctx.accounts.registrar.key(),
+        token_vault: token_vault.key(),
+        restricted_token_mint: restricted_mint_key,
+        bridge_token_mint: token_vault.bridge_token_mint,
+        vault_token_account: token_vault.vault_token_account,
+        hook_program_id,
+        hook_type,
+        extensions_bitmap,
+    });
+
+    emit_cpi!(BridgeTokenMetadataUpdated {
+        token_vault: token_vault.key(),
+        bridge_token_mint: token_vault.bridge_token_mint,
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VerifyReserves<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_vault", token_vault.restricted_token_mint.as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(address = token_vault.vault_token_account @ BridgeError::InvalidTokenVault)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_vault.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch)]
+    pub bridge_token_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Permissionless proof of reserves. Compares the vault ATA balance and the
+/// `total_locked` counter against the bridge mint supply; if either is short,
+/// wraps are paused with `PauseFlags::RESERVE_SHORTFALL_REASON` so the vault
+/// can only be unwound. Lifting the pause is left to a vault manager.
+pub fn verify_reserves(ctx: Context<VerifyReserves>) -> Result<()> {
+    let vault_balance = ctx.accounts.vault_token_account.amount;
+    let bridge_supply = ctx.accounts.bridge_token_mint.supply;
+    let token_vault = &mut ctx.accounts.token_vault;
+    let total_locked = token_vault.total_locked;
+
+    let fully_backed = vault_balance >= bridge_supply && total_locked >= bridge_supply;
+    if !fully_backed && !token_vault.is_paused(PauseFlags::WRAP) {
+        token_vault.pause(PauseFlags::WRAP, PauseFlags::RESERVE_SHORTFALL_REASON);
+
+        msg!("Reserve shortfall, vault for mint {} is now unwrap-only", token_vault.restricted_token_mint);
+    }
+
+    msg!("Vault balance: {}, total locked: {}, bridge supply: {}", vault_balance, total_locked, bridge_supply);
+
+    emit_cpi!(ReservesVerified {
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        vault_balance,
+        total_locked,
+        bridge_supply,
+        fully_backed,
+        safe_mode: token_vault.is_paused(PauseFlags::WRAP),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::require_role;
+use super::admin::{require_no_multisig, require_no_timelock};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ManageRoles<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
+
+/// Adds `roles` to `member`. Once an admin multisig is configured this goes
+/// through an `AdminAction::GrantRole` proposal instead.
+pub fn grant_role(ctx: Context<ManageRoles>, member: Pubkey, roles: u8) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::MANAGE_ROLES)?;
+
+    let held = apply_grant_role(&mut ctx.accounts.bridge_roles, member, roles)?;
+
+    msg!("Granted roles {:#b} to {}", roles, member);
+    msg!("Roles held: {:#b}", held);
+
+    emit_cpi!(RoleGranted {
+        authority: ctx.accounts.admin.key(),
+        member,
+        granted: roles,
+        roles: held,
+    });
+
+    Ok(())
+}
+
+/// Removes `roles` from `member`; subject to the same multisig and timelock
+/// as `grant_role`.
+pub fn revoke_role(ctx: Context<ManageRoles>, member: Pubkey, roles: u8) -> Result<()> {
+    require_role(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.key(),
+        Roles::SUPER_ADMIN,
+    )?;
+    require_no_multisig(&ctx.accounts.bridge_config)?;
+    require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::MANAGE_ROLES)?;
+
+    let held = match apply_revoke_role(&mut ctx.accounts.bridge_roles, member, roles)? {
+        Some(held) => held,
+        None => {
+            msg!("Member holds no roles: {}", member);
+            return Ok(());
+        }
+    };
+
+    msg!("Revoked roles {:#b} from {}", roles, member);
+    msg!("Roles held: {:#b}", held);
+
+    emit_cpi!(RoleRevoked {
+        authority: ctx.accounts.admin.key(),
+        member,
+        revoked: roles,
+        roles: held,
+    });
+
+    Ok(())
+}
+
+/// Grants `roles` and returns the role bits `member` holds afterwards.
+pub(crate) fn apply_grant_role(
+    bridge_roles: &mut BridgeRoles,
+    member: Pubkey,
+    roles: u8,
+) -> Result<u8> {
+    require!(
+        roles != 0 && roles & !Roles::ALL == 0,
+        BridgeError::InvalidRole
+    );
+
+    let held = match bridge_roles.members.iter_mut().find(|assignment| assignment.member == member) {
+        Some(assignment) => {
+            assignment.roles |= roles;
+            assignment.roles
+        }
+        None => {
+            require!(
+                bridge_roles.members.len() < BridgeRoles::MAX_MEMBERS,
+                BridgeError::MaxRoleMembersReached
+            );
+            bridge_roles.members.push(RoleAssignment { member, roles });
+            roles
+        }
+    };
+
+    Ok(held)
+}
+
+/// Revokes `roles` and returns the role bits `member` still holds, or `None`
+/// if `member` held no roles to begin with.
+pub(crate) fn apply_revoke_role(
+    bridge_roles: &mut BridgeRoles,
+    member: Pubkey,
+    roles: u8,
+) -> Result<Option<u8>> {
+    require!(
+        roles != 0 && roles & !Roles::ALL == 0,
+        BridgeError::InvalidRole
+    );
+
+    let pos = match bridge_roles.members.iter().position(|assignment| assignment.member == member) {
+        Some(pos) => pos,
+        None => return Ok(None),
+    };
+
+    bridge_roles.members[pos].roles &= !roles;
+    let held = bridge_roles.members[pos].roles;
+    if held == 0 {
+        bridge_roles.members.remove(pos);
+    }
+
+    Ok(Some(held))
+}
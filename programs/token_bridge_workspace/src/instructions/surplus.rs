@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
+use crate::state::*;
+use crate::error::*;
+use crate::events::*;
+use crate::instructions::admin::require_no_timelock;
+use crate::instructions::multisig::consume_proposal;
+use crate::utils::{calculate_transfer_fee, require_role, transfer_hook_error};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReconcileVaultSurplus<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(mint::token_program = token_2022_program)]
+    pub restricted_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", restricted_token_mint.key().as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(
+        mut,
+        address = token_vault.vault_token_account @ BridgeError::InvalidTokenVault
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = token_vault.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch
+    )]
+    pub bridge_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Destination for `SurplusMode::SweepToTreasury`
+    #[account(
+        mut,
+        token::mint = restricted_token_mint,
+        token::token_program = token_2022_program,
+        constraint = Some(treasury_token_account.owner) == bridge_config.treasury @ BridgeError::TreasuryNotSet
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination for `SurplusMode::MintToRecipient`
+    #[account(
+        mut,
+        token::mint = bridge_token_mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Required together with `proposal` once an admin multisig is configured
+    pub admin_multisig: Option<Account<'info, AdminMultisig>>,
+
+    /// Approved `AdminAction::ReconcileVaultSurplus` proposal, consumed here
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Interface<'info, TokenInterface>,
+}
+
+/// Resolves restricted tokens sent straight to the vault ATA, i.e. the
+/// balance above `total_locked`. The surplus is either swept to the treasury
+/// or backs newly minted bridge tokens for a recipient, after which it counts
+/// as locked. Transfer hook accounts for the sweep go in `remaining_accounts`.
+///
+/// Both outcomes move value, so this is governed like the treasury: a
+/// super-admin subject to the timelock, or an approved multisig proposal
+/// naming the vault, mode and destination once a multisig is configured.
+pub fn reconcile_vault_surplus<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReconcileVaultSurplus<'info>>,
+    mode: SurplusMode
+) -> Result<()> {
+    let destination = match mode {
+        SurplusMode::SweepToTreasury => ctx.accounts.treasury_token_account.as_ref().map(|a| a.key()),
+        SurplusMode::MintToRecipient => ctx.accounts.recipient_token_account.as_ref().map(|a| a.key()),
+    }
+    .ok_or(BridgeError::MissingSurplusDestination)?;
+
+    if ctx.accounts.bridge_config.multisig.is_some() {
+        let action = AdminAction::ReconcileVaultSurplus {
+            token_vault: ctx.accounts.token_vault.key(),
+            mode,
+            destination,
+        };
+        let admin_multisig = ctx.accounts.admin_multisig.as_ref()
+            .ok_or(BridgeError::MultisigRequired)?;
+        let proposal = ctx.accounts.proposal.as_mut()
+            .ok_or(BridgeError::MultisigRequired)?;
+        consume_proposal(&ctx.accounts.bridge_config, admin_multisig, proposal, &action)?;
+
+        let proposal_key = proposal.key();
+        let index = proposal.index;
+        msg!("Admin proposal {} executed", index);
+
+        emit_cpi!(AdminProposalExecuted {
+            proposal: proposal_key,
+            index,
+            executor: ctx.accounts.admin.key(),
+            action,
+        });
+    } else {
+        require_role(
+            &ctx.accounts.bridge_config,
+            &ctx.accounts.bridge_roles,
+            &ctx.accounts.admin.key(),
+            Roles::SUPER_ADMIN,
+        )?;
+        require_no_timelock(&ctx.accounts.bridge_config, GovernanceActions::RECONCILE_SURPLUS)?;
+    }
+
+    let surplus = ctx.accounts.vault_token_account.amount
+        .saturating_sub(ctx.accounts.token_vault.total_locked);
+    require!(surplus > 0, BridgeError::NoVaultSurplus);
+
+    msg!("Vault surplus: {}", surplus);
+
+    match mode {
+        SurplusMode::SweepToTreasury => {
+            let treasury_token_account = ctx.accounts.treasury_token_account.as_ref()
+                .ok_or(BridgeError::MissingSurplusDestination)?;
+
+            let fee = calculate_transfer_fee(
+                &ctx.accounts.restricted_token_mint.to_account_info(),
+                surplus,
+            )?;
+
+            let vault_balance_before = ctx.accounts.vault_token_account.amount;
+            let restricted_mint_key = ctx.accounts.restricted_token_mint.key();
+            let signer_seeds: &[&[u8]] = &[
+                b"token_vault",
+                restricted_mint_key.as_ref(),
+                &[ctx.accounts.token_vault.bump],
+            ];
+
+            invoke_transfer_checked(
+                ctx.accounts.token_2022_program.key,
+                ctx.accounts.vault_token_account.to_account_info(),
+                ctx.accounts.restricted_token_mint.to_account_info(),
+                treasury_token_account.to_account_info(),
+                ctx.accounts.token_vault.to_account_info(),
+                ctx.remaining_accounts,
+                surplus,
+                ctx.accounts.restricted_token_mint.decimals,
+                &[signer_seeds],
+            ).map_err(transfer_hook_error)?;
+
+            // The sweep must only ever take the surplus, never locked tokens.
+            ctx.accounts.vault_token_account.reload()?;
+            let swept = vault_balance_before
+                .checked_sub(ctx.accounts.vault_token_account.amount)
+                .ok_or(BridgeError::MathOverflow)?;
+            require!(swept == surplus, BridgeError::TransferFeeCalculationFailed);
+
+            msg!("Swept {} surplus tokens to treasury (transfer fee: {})", surplus, fee);
+
+            emit_cpi!(VaultSurplusSwept {
+                authority: ctx.accounts.admin.key(),
+                token_vault: ctx.accounts.token_vault.key(),
+                restricted_token_mint: restricted_mint_key,
+                treasury_token_account: treasury_token_account.key(),
+                surplus,
+                transfer_fee: fee,
+            });
+        }
+        SurplusMode::MintToRecipient => {
+            let recipient_token_account = ctx.accounts.recipient_token_account.as_ref()
+                .ok_or(BridgeError::MissingSurplusDestination)?;
+
+            let bridge_signer_seeds: &[&[u8]] = &[
+                b"bridge_config",
+                &[ctx.accounts.bridge_config.bump],
+            ];
+
+            mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: ctx.accounts.bridge_token_mint.to_account_info(),
+                        to: recipient_token_account.to_account_info(),
+                        authority: ctx.accounts.bridge_config.to_account_info(),
+                    },
+                    &[bridge_signer_seeds],
+                ),
+                surplus,
+            )?;
+
+            let token_vault = &mut ctx.accounts.token_vault;
+            token_vault.total_locked = token_vault.total_locked
+                .checked_add(surplus)
+                .ok_or(BridgeError::MathOverflow)?;
+            require!(
+                token_vault.limits.max_total_locked == 0
+                    || token_vault.total_locked <= token_vault.limits.max_total_locked,
+                BridgeError::VaultCapExceeded
+            );
+
+            let bridge_config = &mut ctx.accounts.bridge_config;
+            bridge_config.total_locked_amount = bridge_config.total_locked_amount
+                .checked_add(surplus)
+                .ok_or(BridgeError::MathOverflow)?;
+
+            msg!("Minted {} bridge tokens against vault surplus", surplus);
+
+            emit_cpi!(VaultSurplusMinted {
+                authority: ctx.accounts.admin.key(),
+                token_vault: token_vault.key(),
+                restricted_token_mint: token_vault.restricted_token_mint,
+                recipient_token_account: recipient_token_account.key(),
+                surplus,
+                vault_total_locked: token_vault.total_locked,
+                bridge_total_locked: bridge_config.total_locked_amount,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,25 +1,38 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
     token_interface::{
         Mint, TokenAccount, TokenInterface, 
-        transfer_checked, burn, TransferChecked, Burn
+        burn, Burn, transfer_checked, TransferChecked
     },
 };
 use crate::state::*;
 use crate::error::*;
+use crate::events::*;
+use crate::utils::{
+    calculate_bridge_fee, calculate_transfer_fee, detect_mint_extensions, transfer_hook_error,
+    validate_hook_requirements,
+};
+use super::SimpleWhitelist;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct UnwrapTokens<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    /// CHECK: wallet receiving the restricted tokens, the signer itself for a
+    /// plain unwrap; its eligibility is validated against the vault's hook
+    /// before anything is burned
+    pub recipient: UncheckedAccount<'info>,
+    
     #[account(
         mut,
         seeds = [b"bridge_config"],
         bump = bridge_config.bump,
-        constraint = bridge_config.is_active @ BridgeError::BridgeNotActive
+        constraint = !bridge_config.is_paused(PauseFlags::UNWRAP) @ BridgeError::UnwrapPaused
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
     
@@ -29,20 +42,27 @@ pub struct UnwrapTokens<'info> {
         init_if_needed,
         payer = user,
         associated_token::mint = restricted_token_mint,
-        associated_token::authority = user,
+        associated_token::authority = recipient,
         associated_token::token_program = token_2022_program
     )]
-    pub user_restricted_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         mut,
         seeds = [b"token_vault", restricted_token_mint.key().as_ref()],
         bump = token_vault.bump,
         constraint = token_vault.restricted_token_mint == restricted_token_mint.key() @ BridgeError::InvalidTokenVault,
+        constraint = !token_vault.is_paused(PauseFlags::UNWRAP) @ BridgeError::UnwrapPaused,
         constraint = token_vault.total_locked >= amount @ BridgeError::InsufficientLockedTokens
     )]
     pub token_vault: Account<'info, TokenVault>,
     
+    #[account(
+        seeds = [b"hook_metadata", token_vault.key().as_ref()],
+        bump = hook_metadata.bump
+    )]
+    pub hook_metadata: Account<'info, HookMetadata>,
+    
     #[account(
         mut,
         associated_token::mint = restricted_token_mint,
@@ -54,7 +74,7 @@ pub struct UnwrapTokens<'info> {
     
     #[account(
         mut, 
-        address = bridge_config.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch
+        address = token_vault.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch
     )]
     pub bridge_token_mint: InterfaceAccount<'info, Mint>,
     
@@ -67,8 +87,34 @@ pub struct UnwrapTokens<'info> {
     )]
     pub user_bridge_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// CHECK: Optional whitelist account for hook validation (if needed)
-    pub whitelist: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [b"fee_account", token_vault.key().as_ref()],
+        bump,
+        token::mint = bridge_token_mint,
+        token::authority = bridge_config
+    )]
+    pub fee_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Created on first use, paid by the user; see `UserQuota`.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserQuota::SPACE,
+        seeds = [b"user_quota", token_vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_quota: Box<Account<'info, UserQuota>>,
+    
+    #[account(
+        seeds = [b"whitelist", restricted_token_mint.key().as_ref()],
+        bump = whitelist.bump,
+        constraint = whitelist.mint == restricted_token_mint.key() @ BridgeError::InvalidWhitelistAccount
+    )]
+    pub whitelist: Option<Account<'info, SimpleWhitelist>>,
+    
+    /// CHECK: KYC attestation PDA, validated when the hook type is KYC
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
     
     pub token_program: Interface<'info, TokenInterface>,        
     pub token_2022_program: Interface<'info, TokenInterface>,   
@@ -76,39 +122,133 @@ pub struct UnwrapTokens<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn unwrap_tokens(ctx: Context<UnwrapTokens>, amount: u64) -> Result<()> {
+
+/// Burns bridge tokens and releases the backing restricted tokens to
+/// `recipient`, which may be a third party, e.g. a desk redeeming into a
+/// client's wallet. The recipient must pass the vault's KYC/whitelist checks.
+pub fn unwrap_tokens<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnwrapTokens<'info>>,
+    amount: u64
+) -> Result<()> {
     require!(amount > 0, BridgeError::InvalidBridgeTokenAmount);
     
-    let token_vault_bump = ctx.accounts.token_vault.bump;
-    let restricted_mint_key = ctx.accounts.restricted_token_mint.key();
-    let mint_decimals = ctx.accounts.restricted_token_mint.decimals;
+    let user_quota_bump = ctx.bumps.user_quota;
+    let accounts = &mut *ctx.accounts;
+    
+    // The unwrap fee stays in bridge tokens; only the rest is redeemed.
+    let bridge_fee = calculate_bridge_fee(
+        amount,
+        accounts.token_vault.effective_unwrap_fee_bps(&accounts.bridge_config),
+    )?;
+    let redeemed = amount
+        .checked_sub(bridge_fee)
+        .ok_or(BridgeError::MathOverflow)?;
+    require!(redeemed > 0, BridgeError::InvalidBridgeTokenAmount);
+    
+    // Limits apply to what leaves the vault's locked total.
+    let limits = accounts.token_vault.limits;
+    require!(redeemed >= limits.min_unwrap_amount, BridgeError::AmountBelowMinimum);
+    require!(
+        limits.max_unwrap_amount == 0 || redeemed <= limits.max_unwrap_amount,
+        BridgeError::AmountAboveMaximum
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        accounts.token_vault.unwrap_rate_limit.consume(redeemed, now),
+        BridgeError::RateLimitExceeded
+    );
     
-    if let Some(hook_program_id) = ctx.accounts.token_vault.hook_program_id {
+    let user_quota = &mut accounts.user_quota;
+    user_quota.init_if_new(accounts.token_vault.key(), accounts.user.key(), user_quota_bump);
+    require!(
+        user_quota.record_unwrap(redeemed, &accounts.token_vault.user_quotas, now),
+        BridgeError::UserQuotaExceeded
+    );
+    
+    let token_vault_bump = accounts.token_vault.bump;
+    let restricted_mint_key = accounts.restricted_token_mint.key();
+    let mint_decimals = accounts.restricted_token_mint.decimals;
+    
+    let (_, current_hook_program_id) = detect_mint_extensions(
+        &accounts.restricted_token_mint.to_account_info(),
+    )?;
+    require!(
+        current_hook_program_id == accounts.token_vault.hook_program_id,
+        BridgeError::UnapprovedHookProgram
+    );
+    
+    if let Some(hook_program_id) = accounts.token_vault.hook_program_id {
         require!(
-            ctx.accounts.bridge_config.approved_hook_programs.contains(&hook_program_id),
+            accounts.bridge_config.approved_hook_programs.contains(&hook_program_id),
             BridgeError::UnapprovedHookProgram
         );
         msg!("Hook program validated: {}", hook_program_id);
+    }
+    
+    let recipient = accounts.recipient.key();
+    
+    validate_hook_requirements(
+        &accounts.hook_metadata,
+        &accounts.restricted_token_mint.key(),
+        &recipient,
+        accounts.kyc_attestation.as_ref().map(|info| info.as_ref()),
+    )?;
+    
+    // Checked here rather than left to the hook so that a misconfigured hook
+    // cannot release collateral to a non-whitelisted wallet, and so that a
+    // rejected unwrap fails before any bridge tokens are burned.
+    if accounts.hook_metadata.hook_type == HookType::Whitelist {
+        let whitelist = accounts.whitelist.as_ref()
+            .ok_or(BridgeError::WhitelistAccountRequired)?;
+        require!(
+            whitelist.is_whitelisted(&recipient),
+            BridgeError::RecipientNotWhitelisted
+        );
         
+        msg!("Whitelist validation passed for recipient: {}", recipient);
+    }
+    
+    if bridge_fee > 0 {
+        transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.user_bridge_token_account.to_account_info(),
+                    mint: accounts.bridge_token_mint.to_account_info(),
+                    to: accounts.fee_account.to_account_info(),
+                    authority: accounts.user.to_account_info(),
+                },
+            ),
+            bridge_fee,
+            accounts.bridge_token_mint.decimals,
+        )?;
         
-        if let Some(whitelist) = &ctx.accounts.whitelist {
-            msg!("Whitelist validation passed");
-        }
+        msg!("Accrued {} bridge tokens as unwrap fee", bridge_fee);
     }
     
     burn(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             Burn {
-                mint: ctx.accounts.bridge_token_mint.to_account_info(),
-                from: ctx.accounts.user_bridge_token_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+                mint: accounts.bridge_token_mint.to_account_info(),
+                from: accounts.user_bridge_token_account.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         ),
-        amount,
+        redeemed,
     )?;
     
-    msg!("Burned {} bridge tokens from user", amount);
+    msg!("Burned {} bridge tokens from user", redeemed);
+    
+    let fee = calculate_transfer_fee(
+        &accounts.restricted_token_mint.to_account_info(),
+        redeemed,
+    )?;
+    let expected_received = redeemed
+        .checked_sub(fee)
+        .ok_or(BridgeError::TransferFeeCalculationFailed)?;
+    require!(expected_received > 0, BridgeError::InvalidBridgeTokenAmount);
+    let recipient_balance_before = accounts.recipient_token_account.amount;
     
     let signer_seeds: &[&[u8]] = &[
         b"token_vault",
@@ -116,37 +256,60 @@ pub fn unwrap_tokens(ctx: Context<UnwrapTokens>, amount: u64) -> Result<()> {
         &[token_vault_bump],
     ];
     
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_2022_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault_token_account.to_account_info(),
-                mint: ctx.accounts.restricted_token_mint.to_account_info(),
-                to: ctx.accounts.user_restricted_token_account.to_account_info(),
-                authority: ctx.accounts.token_vault.to_account_info(),
-            },
-            &[signer_seeds],
-        ),
-        amount,
+    invoke_transfer_checked(
+        accounts.token_2022_program.key,
+        accounts.vault_token_account.to_account_info(),
+        accounts.restricted_token_mint.to_account_info(),
+        accounts.recipient_token_account.to_account_info(),
+        accounts.token_vault.to_account_info(),
+        ctx.remaining_accounts,
+        redeemed,
         mint_decimals,
-    ).map_err(|_| BridgeError::HookValidationFailed)?;
+        &[signer_seeds],
+    ).map_err(transfer_hook_error)?;
+    
+    accounts.recipient_token_account.reload()?;
+    let received = accounts.recipient_token_account.amount
+        .checked_sub(recipient_balance_before)
+        .ok_or(BridgeError::MathOverflow)?;
     
-    msg!("Unlocked {} restricted tokens to user", amount);
+    require!(
+        received == expected_received,
+        BridgeError::TransferFeeCalculationFailed
+    );
+    
+    msg!("Unlocked {} restricted tokens to recipient (transfer fee: {})", received, fee);
     msg!("Transfer hook validation (if any) passed successfully");
     
-    let token_vault = &mut ctx.accounts.token_vault;
+    let token_vault = &mut accounts.token_vault;
     token_vault.total_locked = token_vault.total_locked
-        .checked_sub(amount)
+        .checked_sub(redeemed)
         .ok_or(BridgeError::MathOverflow)?;
     
-    let bridge_config = &mut ctx.accounts.bridge_config;
+    let bridge_config = &mut accounts.bridge_config;
     bridge_config.total_locked_amount = bridge_config.total_locked_amount
-        .checked_sub(amount)
+        .checked_sub(redeemed)
         .ok_or(BridgeError::MathOverflow)?;
     
-    msg!("Unwrapped {} tokens. Bridge tokens burned.", amount);
+    msg!("Unwrapped {} tokens. Bridge tokens burned.", redeemed);
     msg!("Remaining locked in vault: {}", token_vault.total_locked);
     msg!("Total locked across bridge: {}", bridge_config.total_locked_amount);
     
+    let unwrapped = Unwrapped {
+        user: accounts.user.key(),
+        recipient,
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        bridge_token_mint: token_vault.bridge_token_mint,
+        burned: redeemed,
+        bridge_fee,
+        transfer_fee: fee,
+        received,
+        vault_total_locked: token_vault.total_locked,
+        bridge_total_locked: bridge_config.total_locked_amount,
+    };
+    
+    emit_cpi!(unwrapped);
+    
     Ok(())
 }
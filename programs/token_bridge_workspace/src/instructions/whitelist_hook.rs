@@ -5,6 +5,7 @@ use spl_tlv_account_resolution::{
 };
 use spl_transfer_hook_interface::instruction::TransferHookInstruction;
 use crate::error::*;
+use crate::events::*;
 
 #[account]
 pub struct SimpleWhitelist {
@@ -13,17 +14,19 @@ pub struct SimpleWhitelist {
     pub users: Vec<Pubkey>,
     pub is_active: bool,
     pub bump: u8,
+    pub pending_authority: Option<Pubkey>,
 }
 
 impl SimpleWhitelist {
     pub const MAX_USERS: usize = 50;
-    pub const SPACE: usize = 8 + 32 + 32 + (4 + 32 * Self::MAX_USERS) + 1 + 1;
+    pub const SPACE: usize = 8 + 32 + 32 + (4 + 32 * Self::MAX_USERS) + 1 + 1 + (1 + 32);
     
     pub fn is_whitelisted(&self, user: &Pubkey) -> bool {
         self.is_active && self.users.contains(user)
     }
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeWhitelist<'info> {
     #[account(mut)]
@@ -53,6 +56,7 @@ pub struct InitializeWhitelist<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ManageWhitelist<'info> {
     #[account(mut)]
@@ -70,6 +74,24 @@ pub struct ManageWhitelist<'info> {
     pub whitelist: Account<'info, SimpleWhitelist>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptWhitelistAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"whitelist", mint.key().as_ref()],
+        bump,
+        has_one = mint,
+        constraint = whitelist.pending_authority.is_some() @ BridgeError::NoPendingAuthority,
+        constraint = whitelist.pending_authority == Some(pending_authority.key()) @ BridgeError::Unauthorized
+    )]
+    pub whitelist: Account<'info, SimpleWhitelist>,
+}
+
 #[derive(Accounts)]
 pub struct WhitelistTransferHook<'info> {
     #[account(
@@ -124,6 +146,7 @@ pub fn initialize_whitelist(ctx: Context<InitializeWhitelist>) -> Result<()> {
     whitelist.users = Vec::new();
     whitelist.is_active = true;
     whitelist.bump = ctx.bumps.whitelist;
+    whitelist.pending_authority = None;
     
     msg!("Whitelist initialized for mint: {}", ctx.accounts.mint.key());
     msg!("Whitelist authority: {}", whitelist.authority);
@@ -140,6 +163,12 @@ pub fn initialize_whitelist(ctx: Context<InitializeWhitelist>) -> Result<()> {
     
     msg!("ExtraAccountMeta list initialized with {} accounts", account_metas.len());
     
+    emit_cpi!(WhitelistInitialized {
+        whitelist: ctx.accounts.whitelist.key(),
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+    
     Ok(())
 }
 
@@ -155,6 +184,13 @@ pub fn add_to_whitelist(ctx: Context<ManageWhitelist>, user: Pubkey) -> Result<(
         whitelist.users.push(user);
         msg!("Added user to whitelist: {}", user);
         msg!("Total whitelisted users: {}", whitelist.users.len());
+        
+        emit_cpi!(WhitelistUserAdded {
+            whitelist: whitelist.key(),
+            mint: whitelist.mint,
+            user,
+            total_users: whitelist.users.len() as u8,
+        });
     } else {
         msg!("User already whitelisted: {}", user);
     }
@@ -169,6 +205,13 @@ pub fn remove_from_whitelist(ctx: Context<ManageWhitelist>, user: Pubkey) -> Res
         whitelist.users.remove(pos);
         msg!("Removed user from whitelist: {}", user);
         msg!("Remaining whitelisted users: {}", whitelist.users.len());
+        
+        emit_cpi!(WhitelistUserRemoved {
+            whitelist: whitelist.key(),
+            mint: whitelist.mint,
+            user,
+            total_users: whitelist.users.len() as u8,
+        });
     } else {
         msg!("User not found in whitelist: {}", user);
     }
@@ -182,6 +225,75 @@ pub fn toggle_whitelist_status(ctx: Context<ManageWhitelist>) -> Result<()> {
     
     msg!("Whitelist status changed to: {}", whitelist.is_active);
     
+    emit_cpi!(WhitelistStatusChanged {
+        whitelist: whitelist.key(),
+        mint: whitelist.mint,
+        is_active: whitelist.is_active,
+    });
+    
+    Ok(())
+}
+
+pub fn propose_whitelist_authority(
+    ctx: Context<ManageWhitelist>, 
+    new_authority: Pubkey
+) -> Result<()> {
+    let whitelist = &mut ctx.accounts.whitelist;
+    
+    require!(
+        new_authority != Pubkey::default() && new_authority != whitelist.authority,
+        BridgeError::InvalidAuthority
+    );
+    
+    whitelist.pending_authority = Some(new_authority);
+    
+    msg!("Whitelist authority transfer proposed to: {}", new_authority);
+    
+    emit_cpi!(WhitelistAuthorityTransferProposed {
+        whitelist: whitelist.key(),
+        mint: whitelist.mint,
+        authority: whitelist.authority,
+        pending_authority: new_authority,
+    });
+    
+    Ok(())
+}
+
+pub fn accept_whitelist_authority(ctx: Context<AcceptWhitelistAuthority>) -> Result<()> {
+    let whitelist = &mut ctx.accounts.whitelist;
+    
+    let old_authority = whitelist.authority;
+    let new_authority = ctx.accounts.pending_authority.key();
+    whitelist.authority = new_authority;
+    whitelist.pending_authority = None;
+    
+    msg!("Whitelist authority updated from {} to {}", old_authority, new_authority);
+    
+    emit_cpi!(WhitelistAuthorityChanged {
+        whitelist: whitelist.key(),
+        mint: whitelist.mint,
+        old_authority,
+        new_authority,
+    });
+    
+    Ok(())
+}
+
+pub fn cancel_whitelist_authority_transfer(ctx: Context<ManageWhitelist>) -> Result<()> {
+    let whitelist = &mut ctx.accounts.whitelist;
+    
+    let cancelled_authority = whitelist.pending_authority
+        .take()
+        .ok_or(BridgeError::NoPendingAuthority)?;
+    
+    msg!("Whitelist authority transfer to {} cancelled", cancelled_authority);
+    
+    emit_cpi!(WhitelistAuthorityTransferCancelled {
+        whitelist: whitelist.key(),
+        mint: whitelist.mint,
+        cancelled_authority,
+    });
+    
     Ok(())
 }
 
@@ -203,6 +315,7 @@ pub fn whitelist_transfer_hook(ctx: Context<WhitelistTransferHook>, amount: u64)
     Ok(())
 }
 
+#[allow(clippy::len_zero)]
 pub fn whitelist_fallback<'info>(
     _program_id: &Pubkey,
     accounts: &'info [AccountInfo<'info>],
@@ -228,8 +341,15 @@ pub fn whitelist_fallback<'info>(
             msg!("Destination token: {}", destination_token.key());
             msg!("Owner: {}", owner.key());
             
+            let (expected_whitelist, _) = Pubkey::find_program_address(
+                &[b"whitelist", mint.key().as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(whitelist.key(), expected_whitelist, BridgeError::InvalidWhitelistAccount);
+            require_keys_eq!(*whitelist.owner, crate::ID, BridgeError::InvalidWhitelistAccount);
+            
             let whitelist_data = whitelist.try_borrow_data()?;
-            let whitelist_account = SimpleWhitelist::try_deserialize(&mut &whitelist_data[8..])?;
+            let whitelist_account = SimpleWhitelist::try_deserialize(&mut &whitelist_data[..])?;
             
             require!(
                 whitelist_account.is_whitelisted(&owner.key()),
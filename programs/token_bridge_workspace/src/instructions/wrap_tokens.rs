@@ -1,14 +1,22 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
     token_interface::{
         Mint, TokenAccount, TokenInterface, 
-        transfer_checked, mint_to, TransferChecked, MintTo
+        mint_to, MintTo
     },
 };
 use crate::state::*;
 use crate::error::*;
+use crate::events::*;
+use crate::utils::{
+    calculate_bridge_fee, calculate_transfer_fee, detect_mint_extensions, transfer_hook_error,
+    validate_hook_requirements,
+};
+use super::SimpleWhitelist;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct WrapTokens<'info> {
@@ -16,9 +24,10 @@ pub struct WrapTokens<'info> {
     pub user: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"bridge_config"],
         bump = bridge_config.bump,
-        constraint = bridge_config.is_active @ BridgeError::BridgeNotActive
+        constraint = !bridge_config.is_paused(PauseFlags::WRAP) @ BridgeError::WrapPaused
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
     
@@ -33,26 +42,31 @@ pub struct WrapTokens<'info> {
     pub user_restricted_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
-        init_if_needed,
-        payer = user,
-        space = TokenVault::SPACE,
+        mut,
         seeds = [b"token_vault", restricted_token_mint.key().as_ref()],
-        bump
+        bump = token_vault.bump,
+        constraint = token_vault.restricted_token_mint == restricted_token_mint.key() @ BridgeError::InvalidTokenVault,
+        constraint = !token_vault.is_paused(PauseFlags::WRAP) @ BridgeError::WrapPaused,
+        constraint = token_vault.is_enabled @ BridgeError::VaultDisabled
     )]
     pub token_vault: Account<'info, TokenVault>,
     
     #[account(
-        init_if_needed,
-        payer = user,
-        associated_token::mint = restricted_token_mint,
-        associated_token::authority = token_vault,
-        associated_token::token_program = token_2022_program
+        seeds = [b"hook_metadata", token_vault.key().as_ref()],
+        bump = hook_metadata.bump
+    )]
+    pub hook_metadata: Account<'info, HookMetadata>,
+    
+    #[account(
+        mut,
+        address = token_vault.vault_token_account @ BridgeError::InvalidTokenVault
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
-        mut, 
-        address = bridge_config.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch
+        mut,
+        address = token_vault.bridge_token_mint @ BridgeError::BridgeTokenMintMismatch,
+        constraint = bridge_token_mint.decimals == restricted_token_mint.decimals @ BridgeError::BridgeTokenDecimalsMismatch
     )]
     pub bridge_token_mint: InterfaceAccount<'info, Mint>,
     
@@ -65,36 +79,60 @@ pub struct WrapTokens<'info> {
     )]
     pub user_bridge_token_account: InterfaceAccount<'info, TokenAccount>,
     
+    #[account(
+        mut,
+        seeds = [b"fee_account", token_vault.key().as_ref()],
+        bump,
+        token::mint = bridge_token_mint,
+        token::authority = bridge_config
+    )]
+    pub fee_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Created on first use, paid by the user; see `UserQuota`.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserQuota::SPACE,
+        seeds = [b"user_quota", token_vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_quota: Box<Account<'info, UserQuota>>,
+    
+    #[account(
+        seeds = [b"whitelist", restricted_token_mint.key().as_ref()],
+        bump = whitelist.bump,
+        constraint = whitelist.mint == restricted_token_mint.key() @ BridgeError::InvalidWhitelistAccount
+    )]
+    pub whitelist: Option<Account<'info, SimpleWhitelist>>,
+    
+    /// CHECK: KYC attestation PDA, validated when the hook type is KYC
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
+    
     pub token_program: Interface<'info, TokenInterface>,        
     pub token_2022_program: Interface<'info, TokenInterface>,  
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn wrap_tokens(ctx: Context<WrapTokens>, amount: u64) -> Result<()> {
+pub fn wrap_tokens<'info>(
+    ctx: Context<'_, '_, '_, 'info, WrapTokens<'info>>,
+    amount: u64
+) -> Result<()> {
     require!(amount > 0, BridgeError::InvalidBridgeTokenAmount);
     
-    let bridge_config_key = ctx.accounts.bridge_config.key();
     let bridge_config_bump = ctx.accounts.bridge_config.bump;
-    let bridge_token_mint_key = ctx.accounts.bridge_config.bridge_token_mint;
-    let restricted_mint_key = ctx.accounts.restricted_token_mint.key();
-    let vault_token_account_key = ctx.accounts.vault_token_account.key();
-    let token_vault_bump = ctx.bumps.token_vault;
     let mint_decimals = ctx.accounts.restricted_token_mint.decimals;
+    let restricted_mint_info = ctx.accounts.restricted_token_mint.to_account_info();
     
     let token_vault = &mut ctx.accounts.token_vault;
-    if token_vault.restricted_token_mint == Pubkey::default() {
-        token_vault.bridge_config = bridge_config_key;
-        token_vault.restricted_token_mint = restricted_mint_key;
-        token_vault.vault_token_account = vault_token_account_key;
-        token_vault.bridge_token_mint = bridge_token_mint_key;
-        token_vault.total_locked = 0;
-        token_vault.hook_program_id = None;
-        token_vault.extensions_bitmap = 0;
-        token_vault.bump = token_vault_bump;
-        
-        msg!("Token vault initialized for mint: {}", restricted_mint_key);
-    }
+    
+    // The mint's hook authority can repoint the hook after the vault was
+    // created; only the program recorded on the vault is trusted.
+    let (_, current_hook_program_id) = detect_mint_extensions(&restricted_mint_info)?;
+    require!(
+        current_hook_program_id == token_vault.hook_program_id,
+        BridgeError::UnapprovedHookProgram
+    );
     
     if let Some(hook_program_id) = token_vault.hook_program_id {
         require!(
@@ -104,21 +142,82 @@ pub fn wrap_tokens(ctx: Context<WrapTokens>, amount: u64) -> Result<()> {
         msg!("Hook program validated: {}", hook_program_id);
     }
     
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_2022_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.user_restricted_token_account.to_account_info(),
-                mint: ctx.accounts.restricted_token_mint.to_account_info(),
-                to: ctx.accounts.vault_token_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
+    validate_hook_requirements(
+        &ctx.accounts.hook_metadata,
+        &restricted_mint_info.key(),
+        &ctx.accounts.user.key(),
+        ctx.accounts.kyc_attestation.as_ref().map(|info| info.as_ref()),
+    )?;
+    
+    if ctx.accounts.hook_metadata.hook_type == HookType::Whitelist {
+        let whitelist = ctx.accounts.whitelist.as_ref()
+            .ok_or(BridgeError::WhitelistAccountRequired)?;
+        require!(
+            whitelist.is_whitelisted(&ctx.accounts.user.key()),
+            BridgeError::SenderNotWhitelisted
+        );
+    }
+    
+    let fee = calculate_transfer_fee(&restricted_mint_info, amount)?;
+    let expected_received = amount
+        .checked_sub(fee)
+        .ok_or(BridgeError::TransferFeeCalculationFailed)?;
+    let vault_balance_before = ctx.accounts.vault_token_account.amount;
+    
+    invoke_transfer_checked(
+        ctx.accounts.token_2022_program.key,
+        ctx.accounts.user_restricted_token_account.to_account_info(),
+        ctx.accounts.restricted_token_mint.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        ctx.remaining_accounts,
         amount,
         mint_decimals,
-    )?;
+        &[],
+    ).map_err(transfer_hook_error)?;
+    
+    ctx.accounts.vault_token_account.reload()?;
+    let received = ctx.accounts.vault_token_account.amount
+        .checked_sub(vault_balance_before)
+        .ok_or(BridgeError::MathOverflow)?;
     
-    msg!("Locked {} restricted tokens in vault", amount);
+    require!(
+        received == expected_received,
+        BridgeError::TransferFeeCalculationFailed
+    );
+    require!(received > 0, BridgeError::InvalidBridgeTokenAmount);
+    
+    // Limits apply to what the vault actually locks, net of the transfer fee.
+    let limits = token_vault.limits;
+    require!(received >= limits.min_wrap_amount, BridgeError::AmountBelowMinimum);
+    require!(
+        limits.max_wrap_amount == 0 || received <= limits.max_wrap_amount,
+        BridgeError::AmountAboveMaximum
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        token_vault.wrap_rate_limit.consume(received, now),
+        BridgeError::RateLimitExceeded
+    );
+    
+    let user_quota = &mut ctx.accounts.user_quota;
+    user_quota.init_if_new(token_vault.key(), ctx.accounts.user.key(), ctx.bumps.user_quota);
+    require!(
+        user_quota.record_wrap(received, &token_vault.user_quotas, now),
+        BridgeError::UserQuotaExceeded
+    );
+    
+    msg!("Locked {} restricted tokens in vault (transfer fee: {})", received, fee);
+    
+    let bridge_fee = calculate_bridge_fee(
+        received,
+        token_vault.effective_wrap_fee_bps(&ctx.accounts.bridge_config),
+    )?;
+    let minted = received
+        .checked_sub(bridge_fee)
+        .ok_or(BridgeError::MathOverflow)?;
+    require!(minted > 0, BridgeError::InvalidBridgeTokenAmount);
     
     let bridge_signer_seeds: &[&[u8]] = &[
         b"bridge_config",
@@ -135,23 +234,57 @@ pub fn wrap_tokens(ctx: Context<WrapTokens>, amount: u64) -> Result<()> {
             },
             &[bridge_signer_seeds],
         ),
-        amount,
+        minted,
     )?;
     
-    msg!("Minted {} bridge tokens to user", amount);
+    msg!("Minted {} bridge tokens to user", minted);
+    
+    if bridge_fee > 0 {
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.bridge_token_mint.to_account_info(),
+                    to: ctx.accounts.fee_account.to_account_info(),
+                    authority: ctx.accounts.bridge_config.to_account_info(),
+                },
+                &[bridge_signer_seeds],
+            ),
+            bridge_fee,
+        )?;
+        
+        msg!("Accrued {} bridge tokens as wrap fee", bridge_fee);
+    }
     
     token_vault.total_locked = token_vault.total_locked
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(BridgeError::MathOverflow)?;
+    require!(
+        limits.max_total_locked == 0 || token_vault.total_locked <= limits.max_total_locked,
+        BridgeError::VaultCapExceeded
+    );
     
     let bridge_config = &mut ctx.accounts.bridge_config;
     bridge_config.total_locked_amount = bridge_config.total_locked_amount
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(BridgeError::MathOverflow)?;
     
-    msg!("Wrapped {} tokens. Bridge tokens minted to user.", amount);
+    msg!("Wrapped {} tokens. Bridge tokens minted to user.", received);
     msg!("Total locked in vault: {}", token_vault.total_locked);
     msg!("Total locked across bridge: {}", bridge_config.total_locked_amount);
     
+    emit_cpi!(Wrapped {
+        user: ctx.accounts.user.key(),
+        token_vault: token_vault.key(),
+        restricted_token_mint: token_vault.restricted_token_mint,
+        bridge_token_mint: token_vault.bridge_token_mint,
+        amount,
+        transfer_fee: fee,
+        bridge_fee,
+        minted,
+        vault_total_locked: token_vault.total_locked,
+        bridge_total_locked: bridge_config.total_locked_amount,
+    });
+    
     Ok(())
 }
\ No newline at end of file
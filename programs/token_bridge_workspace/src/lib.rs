@@ -1,10 +1,19 @@
+#![allow(unexpected_cfgs)]
+// Only for `__idl_resize_account`, which `#[program]` generates at the crate
+// root and which still calls the deprecated `AccountInfo::realloc`. No lint
+// attribute on the program module reaches it.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 pub mod instructions;
 pub mod state;
 pub mod error;
+pub mod events;
+pub mod utils;
 
 use instructions::*;
+use state::{AdminAction, HookType, RateLimitConfig, SurplusMode, UserQuotaConfig, VaultLimits};
 
 declare_id!("Hfvd4ZLYac9wHs8fz4Yo3DCNqU1qRScMY4tu9GwQP7gw");
 
@@ -16,18 +25,35 @@ pub mod token_bridge_workspace {
         instructions::initialize_bridge(ctx)
     }
 
-    pub fn create_bridge_token_mint(ctx: Context<CreateBridgeTokenMint>) -> Result<()> {
-        instructions::create_bridge_token_mint(ctx)
+    pub fn register_vault(ctx: Context<RegisterVault>, hook_type: HookType) -> Result<()> {
+        instructions::register_vault(ctx, hook_type)
     }
 
-    pub fn wrap_tokens(ctx: Context<WrapTokens>, amount: u64) -> Result<()> {
+    pub fn wrap_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, WrapTokens<'info>>,
+        amount: u64
+    ) -> Result<()> {
         instructions::wrap_tokens(ctx, amount)
     }
 
-    pub fn unwrap_tokens(ctx: Context<UnwrapTokens>, amount: u64) -> Result<()> {
+    pub fn unwrap_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnwrapTokens<'info>>,
+        amount: u64
+    ) -> Result<()> {
         instructions::unwrap_tokens(ctx, amount)
     }
 
+    pub fn verify_reserves(ctx: Context<VerifyReserves>) -> Result<()> {
+        instructions::verify_reserves(ctx)
+    }
+
+    pub fn reconcile_vault_surplus<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReconcileVaultSurplus<'info>>,
+        mode: SurplusMode
+    ) -> Result<()> {
+        instructions::reconcile_vault_surplus(ctx, mode)
+    }
+
     pub fn add_approved_hook_program(
         ctx: Context<UpdateBridgeConfig>, 
         hook_program_id: Pubkey
@@ -42,15 +68,169 @@ pub mod token_bridge_workspace {
         instructions::remove_approved_hook_program(ctx, hook_program_id)
     }
 
-    pub fn toggle_bridge_status(ctx: Context<UpdateBridgeConfig>) -> Result<()> {
-        instructions::toggle_bridge_status(ctx)
+    pub fn pause(
+        ctx: Context<UpdateBridgeConfig>, 
+        pause_flags: u8,
+        pause_reason: u16
+    ) -> Result<()> {
+        instructions::pause(ctx, pause_flags, pause_reason)
+    }
+
+    pub fn unpause(ctx: Context<UpdateBridgeConfig>, pause_flags: u8) -> Result<()> {
+        instructions::unpause(ctx, pause_flags)
     }
 
-    pub fn update_bridge_authority(
+    pub fn pause_vault(
+        ctx: Context<UpdateTokenVault>, 
+        pause_flags: u8,
+        pause_reason: u16
+    ) -> Result<()> {
+        instructions::pause_vault(ctx, pause_flags, pause_reason)
+    }
+
+    pub fn unpause_vault(ctx: Context<UpdateTokenVault>, pause_flags: u8) -> Result<()> {
+        instructions::unpause_vault(ctx, pause_flags)
+    }
+
+    pub fn propose_authority(
         ctx: Context<UpdateBridgeConfig>, 
         new_authority: Pubkey
     ) -> Result<()> {
-        instructions::update_bridge_authority(ctx, new_authority)
+        instructions::propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptBridgeAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<UpdateBridgeConfig>) -> Result<()> {
+        instructions::cancel_authority_transfer(ctx)
+    }
+
+    pub fn grant_role(ctx: Context<ManageRoles>, member: Pubkey, roles: u8) -> Result<()> {
+        instructions::grant_role(ctx, member, roles)
+    }
+
+    pub fn revoke_role(ctx: Context<ManageRoles>, member: Pubkey, roles: u8) -> Result<()> {
+        instructions::revoke_role(ctx, member, roles)
+    }
+
+    pub fn initialize_admin_multisig(
+        ctx: Context<InitializeAdminMultisig>, 
+        signers: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        instructions::initialize_admin_multisig(ctx, signers, threshold)
+    }
+
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>, 
+        action: AdminAction
+    ) -> Result<()> {
+        instructions::create_admin_proposal(ctx, action)
+    }
+
+    pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+        instructions::approve_admin_proposal(ctx)
+    }
+
+    pub fn execute_admin_proposal(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+        instructions::execute_admin_proposal(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        instructions::cancel_admin_proposal(ctx)
+    }
+
+    pub fn set_timelock_config(
+        ctx: Context<UpdateBridgeConfig>, 
+        delay: i64,
+        exemptions: u16
+    ) -> Result<()> {
+        instructions::set_timelock_config(ctx, delay, exemptions)
+    }
+
+    pub fn set_treasury(ctx: Context<UpdateBridgeConfig>, treasury: Pubkey) -> Result<()> {
+        instructions::set_treasury(ctx, treasury)
+    }
+
+    pub fn set_fee_config(
+        ctx: Context<UpdateBridgeConfig>, 
+        default_wrap_fee_bps: u16,
+        default_unwrap_fee_bps: u16,
+        max_fee_bps: u16
+    ) -> Result<()> {
+        instructions::set_fee_config(ctx, default_wrap_fee_bps, default_unwrap_fee_bps, max_fee_bps)
+    }
+
+    pub fn set_vault_fees(
+        ctx: Context<UpdateTokenVault>, 
+        wrap_fee_bps: Option<u16>,
+        unwrap_fee_bps: Option<u16>
+    ) -> Result<()> {
+        instructions::set_vault_fees(ctx, wrap_fee_bps, unwrap_fee_bps)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees(ctx, amount)
+    }
+
+    pub fn set_admission_overrides(
+        ctx: Context<UpdateBridgeConfig>, 
+        admission_overrides: u8
+    ) -> Result<()> {
+        instructions::set_admission_overrides(ctx, admission_overrides)
+    }
+
+    pub fn set_permissionless_vault_registration(
+        ctx: Context<UpdateBridgeConfig>, 
+        enabled: bool
+    ) -> Result<()> {
+        instructions::set_permissionless_vault_registration(ctx, enabled)
+    }
+
+    pub fn set_vault_enabled(ctx: Context<UpdateTokenVault>, enabled: bool) -> Result<()> {
+        instructions::set_vault_enabled(ctx, enabled)
+    }
+
+    pub fn set_vault_limits(ctx: Context<UpdateTokenVault>, limits: VaultLimits) -> Result<()> {
+        instructions::set_vault_limits(ctx, limits)
+    }
+
+    pub fn set_vault_rate_limits(
+        ctx: Context<UpdateTokenVault>, 
+        wrap: RateLimitConfig,
+        unwrap: RateLimitConfig
+    ) -> Result<()> {
+        instructions::set_vault_rate_limits(ctx, wrap, unwrap)
+    }
+
+    pub fn set_vault_user_quotas(
+        ctx: Context<UpdateTokenVault>, 
+        user_quotas: UserQuotaConfig
+    ) -> Result<()> {
+        instructions::set_vault_user_quotas(ctx, user_quotas)
+    }
+
+    pub fn set_user_quota(
+        ctx: Context<ManageUserQuota>, 
+        user: Pubkey,
+        wrap_quota: Option<u64>,
+        unwrap_quota: Option<u64>
+    ) -> Result<()> {
+        instructions::set_user_quota(ctx, user, wrap_quota, unwrap_quota)
+    }
+
+    pub fn refresh_bridge_token_metadata(ctx: Context<RefreshBridgeTokenMetadata>) -> Result<()> {
+        instructions::refresh_bridge_token_metadata(ctx)
+    }
+
+    pub fn update_hook_metadata(
+        ctx: Context<UpdateHookMetadata>, 
+        hook_type: HookType,
+        is_active: bool
+    ) -> Result<()> {
+        instructions::update_hook_metadata(ctx, hook_type, is_active)
     }
 
     pub fn initialize_whitelist(ctx: Context<InitializeWhitelist>) -> Result<()> {
@@ -69,10 +249,44 @@ pub mod token_bridge_workspace {
         instructions::toggle_whitelist_status(ctx)
     }
 
+    pub fn propose_whitelist_authority(
+        ctx: Context<ManageWhitelist>, 
+        new_authority: Pubkey
+    ) -> Result<()> {
+        instructions::propose_whitelist_authority(ctx, new_authority)
+    }
+
+    pub fn accept_whitelist_authority(ctx: Context<AcceptWhitelistAuthority>) -> Result<()> {
+        instructions::accept_whitelist_authority(ctx)
+    }
+
+    pub fn cancel_whitelist_authority_transfer(ctx: Context<ManageWhitelist>) -> Result<()> {
+        instructions::cancel_whitelist_authority_transfer(ctx)
+    }
+
     pub fn whitelist_transfer_hook(ctx: Context<WhitelistTransferHook>, amount: u64) -> Result<()> {
         instructions::whitelist_transfer_hook(ctx, amount)
     }
 
+    pub fn migrate_bridge_config(ctx: Context<MigrateBridgeConfig>) -> Result<()> {
+        instructions::migrate_bridge_config(ctx)
+    }
+
+    pub fn migrate_legacy_vault(ctx: Context<MigrateLegacyVault>) -> Result<()> {
+        instructions::migrate_legacy_vault(ctx)
+    }
+
+    pub fn migrate_legacy_whitelist(ctx: Context<MigrateLegacyWhitelist>) -> Result<()> {
+        instructions::migrate_legacy_whitelist(ctx)
+    }
+
+    pub fn redeem_legacy_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemLegacyTokens<'info>>,
+        amount: u64
+    ) -> Result<()> {
+        instructions::redeem_legacy_tokens(ctx, amount)
+    }
+
     pub fn fallback<'info>(
         program_id: &Pubkey,
         accounts: &'info [AccountInfo<'info>],
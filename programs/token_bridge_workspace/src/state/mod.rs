@@ -4,16 +4,321 @@ use anchor_lang::prelude::*;
 pub struct BridgeConfig {
     pub authority: Pubkey,                    // Bridge program authority
     pub bump: u8,                            // PDA bump seed
-    pub bridge_token_mint: Pubkey,           // Standard SPL token mint (for trading)
     pub approved_hook_programs: Vec<Pubkey>, // Whitelisted hook programs
     pub total_locked_amount: u64,            // Total Token2022 tokens locked
-    pub is_active: bool,                     // Bridge operational status
+    pub pause_flags: u8,                     // PauseFlags currently in force
+    pub pause_reason: u16,                   // Operator-defined reason for the pause
+    pub admission_overrides: u8,             // AdmissionOverrides relaxing the mint policy
+    pub permissionless_vault_registration: bool, // Anyone may call register_vault
+    pub pending_authority: Option<Pubkey>,   // Proposed authority awaiting acceptance
+    pub multisig: Option<Pubkey>,            // AdminMultisig gating critical actions
+    pub timelock_delay: i64,                 // Seconds between approval and execution
+    pub timelock_exemptions: u16,            // GovernanceActions that skip the delay
+    pub treasury: Option<Pubkey>,            // Owner of swept surplus and withdrawn fees
+    pub default_wrap_fee_bps: u16,           // Wrap fee for vaults without an override
+    pub default_unwrap_fee_bps: u16,         // Unwrap fee for vaults without an override
+    pub max_fee_bps: u16,                    // Cap applied to every wrap/unwrap fee
+    pub legacy_bridge_token_mint: Option<Pubkey>, // Shared bridge mint of a migrated deployment
 }
 
 impl BridgeConfig {
     pub const MAX_APPROVED_HOOKS: usize = 10;
+    pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+    pub const FEE_BPS_DENOMINATOR: u16 = 10_000;
     
-    pub const SPACE: usize = 8 + 32 + 1 + 32 + (4 + 32 * Self::MAX_APPROVED_HOOKS) + 8 + 1;
+    pub const SPACE: usize = 8 + 32 + 1 + (4 + 32 * Self::MAX_APPROVED_HOOKS) + 8 + 1 + 2 + 1 + 1 + (1 + 32) + (1 + 32) + 8 + 2 + (1 + 32) + 2 + 2 + 2 + (1 + 32);
+    
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.pause_flags & flag != 0
+    }
+    
+    /// Sets `flags` on top of the current ones and records `pause_reason`.
+    /// Reports whether anything changed.
+    pub fn pause(&mut self, flags: u8, pause_reason: u16) -> bool {
+        let pause_flags = self.pause_flags | flags;
+        if self.pause_flags == pause_flags && self.pause_reason == pause_reason {
+            return false;
+        }
+        
+        self.pause_flags = pause_flags;
+        self.pause_reason = pause_reason;
+        
+        true
+    }
+    
+    /// Clears `flags`, leaving every other flag set; the reason is reset
+    /// once nothing is paused. Reports whether anything changed.
+    pub fn unpause(&mut self, flags: u8) -> bool {
+        let pause_flags = self.pause_flags & !flags;
+        if self.pause_flags == pause_flags {
+            return false;
+        }
+        
+        self.pause_flags = pause_flags;
+        if pause_flags == 0 {
+            self.pause_reason = 0;
+        }
+        
+        true
+    }
+    
+    /// Whether `action` (a `GovernanceActions` bit) has to wait out the
+    /// timelock delay.
+    pub fn timelock_applies(&self, action: u16) -> bool {
+        self.timelock_delay > 0 && self.timelock_exemptions & action == 0
+    }
+}
+
+#[account]
+pub struct BridgeRoles {
+    pub bridge_config: Pubkey,               // Reference to bridge config
+    pub members: Vec<RoleAssignment>,        // Role holders and their role bits
+    pub bump: u8,                            // PDA bump seed
+}
+
+impl BridgeRoles {
+    pub const MAX_MEMBERS: usize = 16;
+    
+    pub const SPACE: usize = 8 + 32 + (4 + RoleAssignment::SPACE * Self::MAX_MEMBERS) + 1;
+    
+    /// Role bits held by `member`. The bridge authority implicitly holds
+    /// every role.
+    pub fn roles_of(&self, bridge_config: &BridgeConfig, member: &Pubkey) -> u8 {
+        if *member == bridge_config.authority {
+            return Roles::ALL;
+        }
+        
+        self.granted_roles(member)
+    }
+    
+    /// Role bits explicitly granted to `member`, without the bridge
+    /// authority's implicit ones.
+    pub fn granted_roles(&self, member: &Pubkey) -> u8 {
+        self.members
+            .iter()
+            .find(|assignment| assignment.member == *member)
+            .map(|assignment| assignment.roles)
+            .unwrap_or(0)
+    }
+    
+    /// Whether `member` holds `role`, either directly or through SUPER_ADMIN.
+    pub fn has_role(&self, bridge_config: &BridgeConfig, member: &Pubkey, role: u8) -> bool {
+        self.roles_of(bridge_config, member) & (role | Roles::SUPER_ADMIN) != 0
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoleAssignment {
+    pub member: Pubkey,
+    pub roles: u8,
+}
+
+impl RoleAssignment {
+    pub const SPACE: usize = 32 + 1;
+}
+
+pub struct Roles;
+impl Roles {
+    pub const SUPER_ADMIN: u8 = 1 << 0;
+    pub const PAUSER: u8 = 1 << 1;
+    pub const HOOK_MANAGER: u8 = 1 << 2;
+    pub const VAULT_MANAGER: u8 = 1 << 3;
+    pub const FEE_MANAGER: u8 = 1 << 4;
+    pub const GUARDIAN: u8 = 1 << 5;
+    
+    pub const ALL: u8 = Self::SUPER_ADMIN
+        | Self::PAUSER
+        | Self::HOOK_MANAGER
+        | Self::VAULT_MANAGER
+        | Self::FEE_MANAGER
+        | Self::GUARDIAN;
+}
+
+#[account]
+pub struct AdminMultisig {
+    pub bridge_config: Pubkey,               // Reference to bridge config
+    pub signers: Vec<Pubkey>,                // Keys allowed to propose and approve
+    pub threshold: u8,                       // Approvals required to execute
+    pub proposal_count: u64,                 // Index of the next proposal
+    pub signer_set_version: u64,             // Bumped whenever signers or threshold change
+    pub bump: u8,                            // PDA bump seed
+}
+
+impl AdminMultisig {
+    pub const MAX_SIGNERS: usize = 10;
+    
+    pub const SPACE: usize = 8 + 32 + (4 + 32 * Self::MAX_SIGNERS) + 1 + 8 + 8 + 1;
+    
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+#[account]
+pub struct AdminProposal {
+    pub multisig: Pubkey,                    // Multisig the proposal belongs to
+    pub index: u64,                          // Sequential proposal index
+    pub proposer: Pubkey,                    // Signer that created the proposal
+    pub action: AdminAction,                 // Queued admin action
+    pub approvals: Vec<Pubkey>,              // Signers that approved so far
+    pub eta: Option<i64>,                    // Earliest execution time, set at threshold
+    pub executed: bool,                      // Action already applied
+    pub cancelled: bool,                     // Cancelled by a guardian
+    pub signer_set_version: u64,             // AdminMultisig.signer_set_version at creation
+    pub timelock_delay: i64,                 // BridgeConfig.timelock_delay at creation
+    pub bump: u8,                            // PDA bump seed
+}
+
+impl AdminProposal {
+    pub const SPACE: usize = 8 + 32 + 8 + 32 + AdminAction::SPACE
+        + (4 + 32 * AdminMultisig::MAX_SIGNERS) + (1 + 8) + 1 + 1 + 8 + 8 + 1;
+    
+    /// A proposal only stays valid while the signer set and timelock delay it
+    /// was created under are in force. Approvals therefore always come from
+    /// a single signer set, and a proposal queued before a timelock existed
+    /// cannot skip it.
+    pub fn is_current(&self, multisig: &AdminMultisig, bridge_config: &BridgeConfig) -> bool {
+        self.signer_set_version == multisig.signer_set_version
+            && self.timelock_delay == bridge_config.timelock_delay
+    }
+    
+    /// Approvals from keys that are multisig signers.
+    pub fn approval_count(&self, multisig: &AdminMultisig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| multisig.is_signer(approver))
+            .count()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    AddApprovedHookProgram { hook_program_id: Pubkey },
+    RemoveApprovedHookProgram { hook_program_id: Pubkey },
+    ProposeAuthority { new_authority: Pubkey },
+    CancelAuthorityTransfer,
+    Unpause { pause_flags: u8 },
+    UnpauseVault { token_vault: Pubkey, pause_flags: u8 },
+    SetAdmissionOverrides { admission_overrides: u8 },
+    SetPermissionlessVaultRegistration { enabled: bool },
+    UpdateMultisig { signers: Vec<Pubkey>, threshold: u8 },
+    SetTimelockConfig { delay: i64, exemptions: u16 },
+    SetVaultEnabled { token_vault: Pubkey, enabled: bool },
+    UpdateHookMetadata { token_vault: Pubkey, hook_type: HookType, is_active: bool },
+    SetTreasury { treasury: Pubkey },
+    SetVaultLimits { token_vault: Pubkey, limits: VaultLimits },
+    SetVaultRateLimits { token_vault: Pubkey, wrap: RateLimitConfig, unwrap: RateLimitConfig },
+    SetFeeConfig { default_wrap_fee_bps: u16, default_unwrap_fee_bps: u16, max_fee_bps: u16 },
+    SetVaultFees { token_vault: Pubkey, wrap_fee_bps: Option<u16>, unwrap_fee_bps: Option<u16> },
+    GrantRole { member: Pubkey, roles: u8 },
+    RevokeRole { member: Pubkey, roles: u8 },
+    ReconcileVaultSurplus { token_vault: Pubkey, mode: SurplusMode, destination: Pubkey },
+    SetVaultUserQuotas { token_vault: Pubkey, user_quotas: UserQuotaConfig },
+    SetUserQuota { token_vault: Pubkey, user: Pubkey, wrap_quota: Option<u64>, unwrap_quota: Option<u64> },
+    RemoveMultisig,
+}
+
+impl AdminAction {
+    // Largest variant: UpdateMultisig.
+    pub const SPACE: usize = 1 + (4 + 32 * AdminMultisig::MAX_SIGNERS) + 1;
+    
+    /// Whether the proposal has to wait out the timelock delay. Protective
+    /// actions (disabling a vault, suspending a hook, cancelling an
+    /// authority transfer) never do, whatever their kind's exemption.
+    pub fn timelock_applies(&self, bridge_config: &BridgeConfig) -> bool {
+        let protective = matches!(
+            self,
+            AdminAction::SetVaultEnabled { enabled: false, .. }
+                | AdminAction::UpdateHookMetadata { is_active: false, .. }
+                | AdminAction::CancelAuthorityTransfer
+        );
+        
+        !protective && bridge_config.timelock_applies(self.kind())
+    }
+    
+    /// Whether a guardian may cancel the proposal. Proposals that replace
+    /// the authority or the multisig signers, or revoke roles, can only be
+    /// stopped by withholding approvals, so the keys they remove cannot
+    /// block them.
+    pub fn is_cancellable(&self) -> bool {
+        !matches!(
+            self,
+            AdminAction::ProposeAuthority { .. }
+                | AdminAction::UpdateMultisig { .. }
+                | AdminAction::RemoveMultisig
+                | AdminAction::RevokeRole { .. }
+        )
+    }
+    
+    /// The `GovernanceActions` bit this action falls under.
+    pub fn kind(&self) -> u16 {
+        match self {
+            AdminAction::AddApprovedHookProgram { .. } => GovernanceActions::ADD_HOOK_PROGRAM,
+            AdminAction::RemoveApprovedHookProgram { .. } => GovernanceActions::REMOVE_HOOK_PROGRAM,
+            AdminAction::ProposeAuthority { .. } | AdminAction::CancelAuthorityTransfer => {
+                GovernanceActions::PROPOSE_AUTHORITY
+            }
+            AdminAction::Unpause { .. } => GovernanceActions::UNPAUSE,
+            AdminAction::SetAdmissionOverrides { .. } => GovernanceActions::SET_ADMISSION_OVERRIDES,
+            AdminAction::SetPermissionlessVaultRegistration { .. } => {
+                GovernanceActions::SET_VAULT_REGISTRATION
+            }
+            AdminAction::UpdateMultisig { .. } | AdminAction::RemoveMultisig => {
+                GovernanceActions::UPDATE_MULTISIG
+            }
+            AdminAction::SetTimelockConfig { .. } => GovernanceActions::SET_TIMELOCK,
+            AdminAction::SetVaultEnabled { .. }
+            | AdminAction::UpdateHookMetadata { .. }
+            | AdminAction::UnpauseVault { .. }
+            | AdminAction::SetVaultLimits { .. }
+            | AdminAction::SetVaultRateLimits { .. }
+            | AdminAction::SetVaultUserQuotas { .. }
+            | AdminAction::SetUserQuota { .. } => GovernanceActions::VAULT_POLICY,
+            AdminAction::SetTreasury { .. } => GovernanceActions::SET_TREASURY,
+            AdminAction::SetFeeConfig { .. } | AdminAction::SetVaultFees { .. } => {
+                GovernanceActions::SET_FEES
+            }
+            AdminAction::GrantRole { .. } | AdminAction::RevokeRole { .. } => {
+                GovernanceActions::MANAGE_ROLES
+            }
+            AdminAction::ReconcileVaultSurplus { .. } => GovernanceActions::RECONCILE_SURPLUS,
+        }
+    }
+}
+
+/// Admin action kinds subject to the governance timelock. Bits set in
+/// `BridgeConfig.timelock_exemptions` take effect without the delay; pausing
+/// the bridge, disabling a vault or suspending a hook is never delayed.
+/// SET_TIMELOCK cannot be exempted.
+pub struct GovernanceActions;
+impl GovernanceActions {
+    pub const ADD_HOOK_PROGRAM: u16 = 1 << 0;
+    pub const REMOVE_HOOK_PROGRAM: u16 = 1 << 1;
+    pub const PROPOSE_AUTHORITY: u16 = 1 << 2;
+    pub const UNPAUSE: u16 = 1 << 3;
+    pub const SET_ADMISSION_OVERRIDES: u16 = 1 << 4;
+    pub const SET_VAULT_REGISTRATION: u16 = 1 << 5;
+    pub const UPDATE_MULTISIG: u16 = 1 << 6;
+    pub const SET_TIMELOCK: u16 = 1 << 7;
+    pub const VAULT_POLICY: u16 = 1 << 8;
+    pub const SET_TREASURY: u16 = 1 << 9;
+    pub const SET_FEES: u16 = 1 << 10;
+    pub const MANAGE_ROLES: u16 = 1 << 11;
+    pub const RECONCILE_SURPLUS: u16 = 1 << 12;
+    
+    pub const ALL: u16 = Self::ADD_HOOK_PROGRAM
+        | Self::REMOVE_HOOK_PROGRAM
+        | Self::PROPOSE_AUTHORITY
+        | Self::UNPAUSE
+        | Self::SET_ADMISSION_OVERRIDES
+        | Self::SET_VAULT_REGISTRATION
+        | Self::UPDATE_MULTISIG
+        | Self::SET_TIMELOCK
+        | Self::VAULT_POLICY
+        | Self::SET_TREASURY
+        | Self::SET_FEES
+        | Self::MANAGE_ROLES
+        | Self::RECONCILE_SURPLUS;
 }
 
 #[account]
@@ -21,15 +326,228 @@ pub struct TokenVault {
     pub bridge_config: Pubkey,               // Reference to bridge config
     pub restricted_token_mint: Pubkey,       // Original Token2022 mint
     pub vault_token_account: Pubkey,         // ATA holding locked tokens
-    pub bridge_token_mint: Pubkey,           // Corresponding bridge token mint
+    pub bridge_token_mint: Pubkey,           // Per-mint bridge token mint (PDA)
     pub total_locked: u64,                   // Amount of restricted tokens locked
     pub hook_program_id: Option<Pubkey>,     // Transfer hook program (if any)
     pub extensions_bitmap: u64,              // Bitmap of detected extensions
     pub bump: u8,                            // PDA bump seed
+    pub is_enabled: bool,                    // Wraps allowed (unwraps always are)
+    pub pause_flags: u8,                     // PauseFlags (WRAP/UNWRAP) for this vault
+    pub pause_reason: u16,                   // Operator-defined reason for the pause
+    pub wrap_fee_bps: Option<u16>,           // Overrides the bridge default wrap fee
+    pub unwrap_fee_bps: Option<u16>,         // Overrides the bridge default unwrap fee
+    pub limits: VaultLimits,                 // Supply cap and per-transaction bounds
+    pub wrap_rate_limit: RateLimit,          // Token bucket for wraps
+    pub unwrap_rate_limit: RateLimit,        // Token bucket for unwraps
+    pub user_quotas: UserQuotaConfig,        // Default daily quotas per user
+    pub legacy_locked: u64,                  // Part of total_locked backing legacy bridge tokens
 }
 
 impl TokenVault {
-    pub const SPACE: usize = 8 + 32 + 32 + 32 + 32 + 8 + (1 + 32) + 8 + 1;
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 32 + 8 + (1 + 32) + 8 + 1 + 1 + 1 + 2 + (1 + 2) + (1 + 2) + VaultLimits::SPACE
+        + RateLimit::SPACE * 2 + UserQuotaConfig::SPACE + 8;
+    
+    /// Wrap fee in basis points, capped by `BridgeConfig.max_fee_bps`.
+    pub fn effective_wrap_fee_bps(&self, bridge_config: &BridgeConfig) -> u16 {
+        self.wrap_fee_bps
+            .unwrap_or(bridge_config.default_wrap_fee_bps)
+            .min(bridge_config.max_fee_bps)
+    }
+    
+    /// Unwrap fee in basis points, capped by `BridgeConfig.max_fee_bps`.
+    pub fn effective_unwrap_fee_bps(&self, bridge_config: &BridgeConfig) -> u16 {
+        self.unwrap_fee_bps
+            .unwrap_or(bridge_config.default_unwrap_fee_bps)
+            .min(bridge_config.max_fee_bps)
+    }
+    
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.pause_flags & flag != 0
+    }
+    
+    /// Sets `flags` on top of the current ones and records `pause_reason`.
+    /// Reports whether anything changed.
+    pub fn pause(&mut self, flags: u8, pause_reason: u16) -> bool {
+        let pause_flags = self.pause_flags | flags;
+        if self.pause_flags == pause_flags && self.pause_reason == pause_reason {
+            return false;
+        }
+        
+        self.pause_flags = pause_flags;
+        self.pause_reason = pause_reason;
+        
+        true
+    }
+    
+    /// Clears `flags`, leaving every other flag set; the reason is reset
+    /// once nothing is paused. Reports whether anything changed.
+    pub fn unpause(&mut self, flags: u8) -> bool {
+        let pause_flags = self.pause_flags & !flags;
+        if self.pause_flags == pause_flags {
+            return false;
+        }
+        
+        self.pause_flags = pause_flags;
+        if pause_flags == 0 {
+            self.pause_reason = 0;
+        }
+        
+        true
+    }
+}
+
+/// Exposure limits for a vault. A zero maximum means unlimited.
+///
+/// These limits, the rate limits and the user quotas are all charged on the
+/// amount that moves `TokenVault.total_locked`: on wrap what the vault
+/// received after the Token-2022 transfer fee, on unwrap what is redeemed
+/// after the bridge fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VaultLimits {
+    pub max_total_locked: u64,               // Cap on TokenVault.total_locked
+    pub min_wrap_amount: u64,
+    pub max_wrap_amount: u64,
+    pub min_unwrap_amount: u64,
+    pub max_unwrap_amount: u64,
+}
+
+impl VaultLimits {
+    pub const SPACE: usize = 8 * 5;
+    
+    pub fn is_valid(&self) -> bool {
+        (self.max_wrap_amount == 0 || self.min_wrap_amount <= self.max_wrap_amount)
+            && (self.max_unwrap_amount == 0 || self.min_unwrap_amount <= self.max_unwrap_amount)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    pub capacity: u64,                       // Bucket size; 0 disables the limit
+    pub refill_per_second: u64,              // Tokens returned to the bucket per second
+}
+
+/// Token bucket bounding how fast tokens can move through a vault. The
+/// bucket refills continuously from `last_refill` up to `capacity`. Charged
+/// on the same basis as `VaultLimits`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    pub config: RateLimitConfig,
+    pub available: u64,                      // Tokens left in the bucket
+    pub last_refill: i64,                    // Unix timestamp of the last refill
+}
+
+impl RateLimit {
+    pub const SPACE: usize = 8 + 8 + 8 + 8;
+    
+    /// A full bucket starting at `now`.
+    pub fn new(config: RateLimitConfig, now: i64) -> Self {
+        Self {
+            config,
+            available: config.capacity,
+            last_refill: now,
+        }
+    }
+    
+    /// Refills the bucket up to `now` and takes `amount` out of it. Always
+    /// succeeds while the limit is disabled.
+    pub fn consume(&mut self, amount: u64, now: i64) -> bool {
+        if self.config.capacity == 0 {
+            return true;
+        }
+        
+        let elapsed = now.saturating_sub(self.last_refill).max(0) as u64;
+        let refill = elapsed.saturating_mul(self.config.refill_per_second);
+        self.available = self.available
+            .saturating_add(refill)
+            .min(self.config.capacity);
+        self.last_refill = now;
+        
+        if amount > self.available {
+            return false;
+        }
+        self.available -= amount;
+        
+        true
+    }
+}
+
+/// Daily per-user quotas applied to users without an override. Zero means
+/// unlimited. Charged on the same basis as `VaultLimits`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UserQuotaConfig {
+    pub daily_wrap_quota: u64,
+    pub daily_unwrap_quota: u64,
+}
+
+impl UserQuotaConfig {
+    pub const SPACE: usize = 8 + 8;
+}
+
+/// Per-user daily counters for one vault. Created on the user's first wrap
+/// or unwrap through the vault and paid for by the user: rent for
+/// `UserQuota::SPACE` bytes, not refunded. Wrap and unwrap require it even
+/// when no quotas are configured, so a per-user override cannot be dodged by
+/// leaving the account out.
+#[account]
+pub struct UserQuota {
+    pub token_vault: Pubkey,                 // Vault the quota applies to
+    pub user: Pubkey,                        // Wallet the quota applies to
+    pub window_start: i64,                   // Start of the current UTC day
+    pub wrapped: u64,                        // Amount wrapped in the current window
+    pub unwrapped: u64,                      // Amount unwrapped in the current window
+    pub wrap_quota: Option<u64>,             // Overrides the vault default
+    pub unwrap_quota: Option<u64>,           // Overrides the vault default
+    pub bump: u8,                            // PDA bump seed
+}
+
+impl UserQuota {
+    pub const WINDOW: i64 = 24 * 60 * 60;
+    
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 8 + (1 + 8) + (1 + 8) + 1;
+    
+    /// Sets up a freshly created quota account; no-op once initialized.
+    pub fn init_if_new(&mut self, token_vault: Pubkey, user: Pubkey, bump: u8) {
+        if self.token_vault == Pubkey::default() {
+            self.token_vault = token_vault;
+            self.user = user;
+            self.bump = bump;
+        }
+    }
+    
+    /// Adds `amount` to today's wrapped total, failing if that exceeds the
+    /// user's quota.
+    pub fn record_wrap(&mut self, amount: u64, defaults: &UserQuotaConfig, now: i64) -> bool {
+        self.roll_window(now);
+        let quota = self.wrap_quota.unwrap_or(defaults.daily_wrap_quota);
+        Self::record(&mut self.wrapped, amount, quota)
+    }
+    
+    /// Adds `amount` to today's unwrapped total, failing if that exceeds the
+    /// user's quota.
+    pub fn record_unwrap(&mut self, amount: u64, defaults: &UserQuotaConfig, now: i64) -> bool {
+        self.roll_window(now);
+        let quota = self.unwrap_quota.unwrap_or(defaults.daily_unwrap_quota);
+        Self::record(&mut self.unwrapped, amount, quota)
+    }
+    
+    fn roll_window(&mut self, now: i64) {
+        let window_start = now - now.rem_euclid(Self::WINDOW);
+        if window_start != self.window_start {
+            self.window_start = window_start;
+            self.wrapped = 0;
+            self.unwrapped = 0;
+        }
+    }
+    
+    fn record(used: &mut u64, amount: u64, quota: u64) -> bool {
+        let total = used.saturating_add(amount);
+        if quota != 0 && total > quota {
+            return false;
+        }
+        *used = total;
+        
+        true
+    }
 }
 
 #[account]
@@ -46,7 +564,7 @@ impl HookMetadata {
     pub const SPACE: usize = 8 + 32 + 32 + 32 + 1 + 1 + 1;
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HookType {
     Whitelist,
     KYC,
@@ -54,6 +572,14 @@ pub enum HookType {
     Custom,
 }
 
+/// What `reconcile_vault_surplus` does with restricted tokens that reached a
+/// vault without going through `wrap_tokens`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurplusMode {
+    SweepToTreasury,
+    MintToRecipient,
+}
+
 pub struct ExtensionFlags;
 impl ExtensionFlags {
     pub const TRANSFER_HOOK: u64 = 1 << 0;
@@ -61,4 +587,41 @@ impl ExtensionFlags {
     pub const DEFAULT_ACCOUNT_STATE: u64 = 1 << 2;
     pub const NON_TRANSFERABLE: u64 = 1 << 3;
     pub const METADATA: u64 = 1 << 4;
+    pub const PERMANENT_DELEGATE: u64 = 1 << 5;
+    pub const MINT_CLOSE_AUTHORITY: u64 = 1 << 6;
+    pub const CONFIDENTIAL_TRANSFER: u64 = 1 << 7;
+    pub const INTEREST_BEARING: u64 = 1 << 8;
+    pub const TOKEN_GROUP: u64 = 1 << 9;
+    pub const CONFIDENTIAL_MINT_BURN: u64 = 1 << 10;
+}
+
+/// Operations that can be paused independently, bridge-wide through
+/// `BridgeConfig.pause_flags` or per vault through `TokenVault.pause_flags`.
+/// REGISTRATION only applies at the bridge level.
+pub struct PauseFlags;
+impl PauseFlags {
+    pub const WRAP: u8 = 1 << 0;
+    pub const UNWRAP: u8 = 1 << 1;
+    pub const REGISTRATION: u8 = 1 << 2;
+    
+    pub const VAULT: u8 = Self::WRAP | Self::UNWRAP;
+    pub const ALL: u8 = Self::WRAP | Self::UNWRAP | Self::REGISTRATION;
+    
+    /// Reason code recorded when `verify_reserves` finds a vault backed by
+    /// less than its bridge mint supply and pauses wraps.
+    pub const RESERVE_SHORTFALL_REASON: u16 = u16::MAX;
+}
+
+/// Mint admission policies the bridge authority may relax. Every policy is
+/// enforced unless its bit is set in `BridgeConfig.admission_overrides`;
+/// NonTransferable mints are always rejected.
+pub struct AdmissionOverrides;
+impl AdmissionOverrides {
+    pub const ALLOW_PERMANENT_DELEGATE: u8 = 1 << 0;
+    pub const ALLOW_FREEZE_AUTHORITY: u8 = 1 << 1;
+    pub const ALLOW_CONFIDENTIAL_MINT_BURN: u8 = 1 << 2;
+    
+    pub const ALL: u8 = Self::ALLOW_PERMANENT_DELEGATE
+        | Self::ALLOW_FREEZE_AUTHORITY
+        | Self::ALLOW_CONFIDENTIAL_MINT_BURN;
 }
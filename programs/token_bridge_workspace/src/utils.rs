@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::{
+    types::DataV2, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        default_account_state::DefaultAccountState, transfer_fee::TransferFeeConfig,
+        transfer_hook, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::{AccountState, Mint as Token2022Mint},
+};
+use spl_transfer_hook_interface::error::TransferHookError;
+use crate::error::*;
+use crate::state::{
+    AdmissionOverrides, BridgeConfig, BridgeRoles, ExtensionFlags, HookMetadata, HookType,
+};
+
+/// Fee Token-2022 will withhold when `amount` is transferred out of an
+/// account of `mint_info` during the current epoch. Mints without the
+/// TransferFeeConfig extension always return 0.
+pub fn calculate_transfer_fee(mint_info: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+
+    let fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(BridgeError::TransferFeeCalculationFailed)?,
+        Err(_) => 0,
+    };
+
+    Ok(fee)
+}
+
+/// Bridge fee for `amount` at `fee_bps` basis points, rounded down.
+pub fn calculate_bridge_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(BridgeError::MathOverflow)?
+        / BridgeConfig::FEE_BPS_DENOMINATOR as u128;
+
+    u64::try_from(fee).map_err(|_| BridgeError::MathOverflow.into())
+}
+
+/// Fails with `MissingRole` unless `member` holds `role` (or SUPER_ADMIN) in
+/// `bridge_roles`; the bridge authority always passes.
+pub fn require_role(
+    bridge_config: &BridgeConfig,
+    bridge_roles: &BridgeRoles,
+    member: &Pubkey,
+    role: u8,
+) -> Result<()> {
+    require!(
+        bridge_roles.has_role(bridge_config, member, role),
+        BridgeError::MissingRole
+    );
+
+    Ok(())
+}
+
+/// Parses the TLV extensions of a Token-2022 mint into an `ExtensionFlags`
+/// bitmap, together with the transfer hook program id when one is set.
+pub fn detect_mint_extensions(mint_info: &AccountInfo) -> Result<(u64, Option<Pubkey>)> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+
+    let mut bitmap = 0u64;
+    for extension in mint.get_extension_types()? {
+        bitmap |= match extension {
+            ExtensionType::TransferHook => ExtensionFlags::TRANSFER_HOOK,
+            ExtensionType::TransferFeeConfig => ExtensionFlags::TRANSFER_FEE,
+            ExtensionType::DefaultAccountState => ExtensionFlags::DEFAULT_ACCOUNT_STATE,
+            ExtensionType::NonTransferable => ExtensionFlags::NON_TRANSFERABLE,
+            ExtensionType::MetadataPointer | ExtensionType::TokenMetadata => {
+                ExtensionFlags::METADATA
+            }
+            ExtensionType::PermanentDelegate => ExtensionFlags::PERMANENT_DELEGATE,
+            ExtensionType::MintCloseAuthority => ExtensionFlags::MINT_CLOSE_AUTHORITY,
+            ExtensionType::ConfidentialTransferMint
+            | ExtensionType::ConfidentialTransferFeeConfig => ExtensionFlags::CONFIDENTIAL_TRANSFER,
+            ExtensionType::ConfidentialMintBurn => ExtensionFlags::CONFIDENTIAL_MINT_BURN,
+            ExtensionType::InterestBearingConfig => ExtensionFlags::INTEREST_BEARING,
+            ExtensionType::GroupPointer
+            | ExtensionType::TokenGroup
+            | ExtensionType::GroupMemberPointer
+            | ExtensionType::TokenGroupMember => ExtensionFlags::TOKEN_GROUP,
+            _ => 0,
+        };
+    }
+
+    Ok((bitmap, transfer_hook::get_program_id(&mint)))
+}
+
+/// Mint admission policy applied when a vault is created. Rejects mints the
+/// bridge cannot safely custody unless the matching `AdmissionOverrides` bit
+/// is set: NonTransferable (never allowed), PermanentDelegate, a freeze
+/// authority or frozen-by-default accounts, and confidential-only supply.
+/// Mints that merely allow confidential transfers are admitted, since their
+/// public balances still move with `transfer_checked`.
+pub fn check_mint_admission(
+    mint_info: &AccountInfo,
+    extensions_bitmap: u64,
+    admission_overrides: u8,
+) -> Result<()> {
+    let allowed = |flag: u8| admission_overrides & flag != 0;
+
+    require!(
+        extensions_bitmap & ExtensionFlags::NON_TRANSFERABLE == 0,
+        BridgeError::NonTransferableToken
+    );
+
+    if !allowed(AdmissionOverrides::ALLOW_PERMANENT_DELEGATE) {
+        require!(
+            extensions_bitmap & ExtensionFlags::PERMANENT_DELEGATE == 0,
+            BridgeError::PermanentDelegateNotAllowed
+        );
+    }
+
+    // ConfidentialMintBurn mints only ever credit encrypted balances, so
+    // there is nothing a public transfer could lock in the vault.
+    if !allowed(AdmissionOverrides::ALLOW_CONFIDENTIAL_MINT_BURN) {
+        require!(
+            extensions_bitmap & ExtensionFlags::CONFIDENTIAL_MINT_BURN == 0,
+            BridgeError::ConfidentialOnlyMint
+        );
+    }
+
+    if !allowed(AdmissionOverrides::ALLOW_FREEZE_AUTHORITY) {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+
+        require!(
+            mint.base.freeze_authority.is_none(),
+            BridgeError::FreezeAuthorityNotAllowed
+        );
+
+        if let Ok(default_state) = mint.get_extension::<DefaultAccountState>() {
+            require!(
+                default_state.state != AccountState::Frozen as u8,
+                BridgeError::FreezeAuthorityNotAllowed
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Leading base58 characters of the mint address used as a fallback symbol.
+const FALLBACK_SYMBOL_LENGTH: usize = 6;
+
+/// Metaplex metadata for a vault's bridge mint, mirrored from the restricted
+/// mint's Token-2022 `TokenMetadata` extension: "Wrapped <SYMBOL>", "w<SYMBOL>"
+/// and the same URI. Mints without a symbol use the first characters of
+/// their address instead, so every bridge mint stays distinguishable.
+/// Fields are truncated to the Metaplex length limits.
+pub fn bridge_token_metadata(restricted_mint_info: &AccountInfo) -> Result<DataV2> {
+    let mint_data = restricted_mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+
+    let address_symbol = || restricted_mint_info.key.to_string()[..FALLBACK_SYMBOL_LENGTH].to_string();
+    let (symbol, uri) = match mint.get_variable_len_extension::<TokenMetadata>() {
+        Ok(metadata) if !metadata.symbol.is_empty() => (metadata.symbol, metadata.uri),
+        Ok(metadata) => (address_symbol(), metadata.uri),
+        Err(_) => (address_symbol(), String::new()),
+    };
+
+    Ok(DataV2 {
+        name: truncate_utf8(&format!("Wrapped {}", symbol), MAX_NAME_LENGTH),
+        symbol: truncate_utf8(&format!("w{}", symbol), MAX_SYMBOL_LENGTH),
+        uri: truncate_utf8(&uri, MAX_URI_LENGTH),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    })
+}
+
+fn truncate_utf8(value: &str, max_len: usize) -> String {
+    let mut end = value.len().min(max_len);
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
+/// Bridge-side validation for the vault's hook, run before any tokens move.
+/// A suspended hook blocks the vault; KYC hooks require an attestation PDA
+/// `["kyc", mint, party]` owned by the hook program. Whitelist membership is
+/// checked by the callers for `HookType::Whitelist`, TransferLimit and
+/// Custom hooks are left to the hook itself.
+pub fn validate_hook_requirements(
+    hook_metadata: &HookMetadata,
+    mint: &Pubkey,
+    party: &Pubkey,
+    kyc_attestation: Option<&AccountInfo>,
+) -> Result<()> {
+    require!(hook_metadata.is_active, BridgeError::HookSuspended);
+
+    if hook_metadata.hook_program_id == Pubkey::default() {
+        return Ok(());
+    }
+
+    if hook_metadata.hook_type == HookType::KYC {
+        let attestation_info = kyc_attestation.ok_or(BridgeError::KycAttestationMissing)?;
+        let (expected_attestation, _) = Pubkey::find_program_address(
+            &[b"kyc", mint.as_ref(), party.as_ref()],
+            &hook_metadata.hook_program_id,
+        );
+        require_keys_eq!(
+            attestation_info.key(),
+            expected_attestation,
+            BridgeError::KycAttestationMissing
+        );
+        require!(
+            *attestation_info.owner == hook_metadata.hook_program_id
+                && !attestation_info.data_is_empty(),
+            BridgeError::KycAttestationMissing
+        );
+    }
+
+    Ok(())
+}
+
+/// Error for a failed `spl_token_2022::onchain::invoke_transfer_checked`.
+/// The transfer hook interface reports a hook program missing from the
+/// additional accounts as `IncorrectAccount`; every other error, e.g. an
+/// unresolvable extra account, is returned unchanged.
+pub fn transfer_hook_error(err: ProgramError) -> Error {
+    if err == TransferHookError::IncorrectAccount.into() {
+        return BridgeError::MissingTransferHookAccounts.into();
+    }
+
+    err.into()
+}
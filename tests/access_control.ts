@@ -0,0 +1,231 @@
+import {
+  PublicKey,
+  Keypair,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  program,
+  wallet,
+  connection,
+  bridgeConfig,
+  bridgeRoles,
+  programData,
+  airdrop,
+  expectFailure,
+} from "./helpers";
+
+describe("access_control_test", () => {
+  // Test keypairs
+  const newAuthority = new Keypair();
+  const outsider = new Keypair();
+
+  // Roles bits
+  const SUPER_ADMIN = 1 << 0;
+  const HOOK_MANAGER = 1 << 2;
+
+  const rolesOf = async (member: PublicKey) => {
+    const roles = await program.account.bridgeRoles.fetch(bridgeRoles);
+    const assignment = roles.members.find((entry) => entry.member.equals(member));
+    return assignment ? assignment.roles : 0;
+  };
+
+  before(async () => {
+    console.log("🔧 Setting up access control test...");
+
+    await airdrop(newAuthority, outsider);
+  });
+
+  // This suite sorts first, so on a fresh validator it is the one that
+  // initializes the bridge.
+  it("Only lets the program upgrade authority initialize the bridge", async () => {
+    console.log("🚀 Initializing the bridge...");
+
+    if (await connection.getAccountInfo(bridgeConfig)) {
+      console.log("ℹ️ Bridge already initialized");
+      return;
+    }
+
+    await expectFailure(
+      program.methods
+        .initializeBridge()
+        .accountsPartial({
+          authority: outsider.publicKey,
+          bridgeProgram: program.programId,
+          programData,
+        })
+        .signers([outsider])
+        .rpc(),
+      "Unauthorized"
+    );
+
+    await program.methods
+      .initializeBridge()
+      .accountsPartial({
+        bridgeProgram: program.programId,
+        programData,
+      })
+      .rpc();
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.authority.equals(wallet.publicKey)).to.be.true;
+    console.log("✅ Bridge initialized by the upgrade authority");
+  });
+
+  it("Transfers the bridge authority in two steps", async () => {
+    console.log("🔑 Proposing a new bridge authority...");
+
+    await program.methods.proposeAuthority(newAuthority.publicKey).rpc();
+
+    let config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.authority.equals(wallet.publicKey)).to.be.true;
+    expect(config.pendingAuthority.equals(newAuthority.publicKey)).to.be.true;
+
+    // Only the proposed key can accept
+    await expectFailure(
+      program.methods
+        .acceptAuthority()
+        .accounts({ pendingAuthority: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "Unauthorized"
+    );
+
+    await program.methods
+      .acceptAuthority()
+      .accounts({ pendingAuthority: newAuthority.publicKey })
+      .signers([newAuthority])
+      .rpc();
+
+    config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.authority.equals(newAuthority.publicKey)).to.be.true;
+    expect(config.pendingAuthority).to.be.null;
+    console.log("✅ Authority accepted by:", newAuthority.publicKey.toString());
+
+    // Hand the bridge back so the remaining suites keep working
+    await program.methods
+      .proposeAuthority(wallet.publicKey)
+      .accounts({ admin: newAuthority.publicKey })
+      .signers([newAuthority])
+      .rpc();
+    await program.methods
+      .acceptAuthority()
+      .accounts({ pendingAuthority: wallet.publicKey })
+      .rpc();
+
+    config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.authority.equals(wallet.publicKey)).to.be.true;
+  });
+
+  it("Cancels a pending authority transfer", async () => {
+    console.log("🚫 Cancelling a proposed authority transfer...");
+
+    await expectFailure(program.methods.cancelAuthorityTransfer().rpc(), "NoPendingAuthority");
+
+    await program.methods.proposeAuthority(newAuthority.publicKey).rpc();
+    await program.methods.cancelAuthorityTransfer().rpc();
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.pendingAuthority).to.be.null;
+
+    await expectFailure(
+      program.methods
+        .acceptAuthority()
+        .accounts({ pendingAuthority: newAuthority.publicKey })
+        .signers([newAuthority])
+        .rpc(),
+      "NoPendingAuthority"
+    );
+  });
+
+  it("Gates admin instructions on granted roles", async () => {
+    console.log("🎭 Granting and revoking the hook manager role...");
+
+    const hookProgram = new Keypair().publicKey;
+    const addHookAsOutsider = () =>
+      program.methods
+        .addApprovedHookProgram(hookProgram)
+        .accounts({ admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc();
+
+    await expectFailure(addHookAsOutsider(), "MissingRole");
+
+    await program.methods.grantRole(outsider.publicKey, HOOK_MANAGER).rpc();
+    expect(await rolesOf(outsider.publicKey)).to.equal(HOOK_MANAGER);
+
+    await addHookAsOutsider();
+    let config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.approvedHookPrograms.some((id) => id.equals(hookProgram))).to.be.true;
+    console.log("✅ Hook manager approved a hook program");
+
+    // A hook manager cannot hand out roles
+    await expectFailure(
+      program.methods
+        .grantRole(outsider.publicKey, SUPER_ADMIN)
+        .accounts({ admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "MissingRole"
+    );
+
+    await program.methods.revokeRole(outsider.publicKey, HOOK_MANAGER).rpc();
+    expect(await rolesOf(outsider.publicKey)).to.equal(0);
+
+    await expectFailure(
+      program.methods
+        .removeApprovedHookProgram(hookProgram)
+        .accounts({ admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "MissingRole"
+    );
+
+    await program.methods.removeApprovedHookProgram(hookProgram).rpc();
+    config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.approvedHookPrograms.some((id) => id.equals(hookProgram))).to.be.false;
+  });
+
+  it("Restricts pausing to pausers and unpausing to super admins", async () => {
+    console.log("⏸️ Pausing from accounts without roles...");
+
+    const PAUSE_REGISTRATION = 1 << 2;
+    const PAUSER = 1 << 1;
+
+    await expectFailure(
+      program.methods
+        .pause(PAUSE_REGISTRATION, 1)
+        .accounts({ admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "MissingRole"
+    );
+
+    await program.methods.grantRole(outsider.publicKey, PAUSER).rpc();
+    try {
+      await program.methods
+        .pause(PAUSE_REGISTRATION, 1)
+        .accounts({ admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc();
+
+      const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+      expect(config.pauseFlags).to.equal(PAUSE_REGISTRATION);
+      console.log("✅ Pauser paused vault registration");
+
+      await expectFailure(
+        program.methods
+          .unpause(PAUSE_REGISTRATION)
+          .accounts({ admin: outsider.publicKey })
+          .signers([outsider])
+          .rpc(),
+        "MissingRole"
+      );
+    } finally {
+      await program.methods.unpause(PAUSE_REGISTRATION).rpc();
+      await program.methods.revokeRole(outsider.publicKey, PAUSER).rpc();
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.pauseFlags).to.equal(0);
+  });
+});
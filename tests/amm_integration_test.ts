@@ -41,7 +41,11 @@ describe("amm_integration_test", () => {
     program.programId
   );
 
-  let bridgeTokenMint: PublicKey;
+  // Each restricted mint gets its own bridge token mint PDA
+  const [bridgeTokenMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from("bridge_token_mint"), restrictedMint.publicKey.toBuffer()],
+    program.programId
+  );
 
   before(async () => {
     console.log("🌐 AMM INTEGRATION TEST");
@@ -67,9 +71,8 @@ describe("amm_integration_test", () => {
 
   it("🚀 Setup: Verify bridge system", async () => {
     const config = await program.account.bridgeConfig.fetch(bridgeConfig);
-    bridgeTokenMint = config.bridgeTokenMint;
     
-    console.log("✅ Bridge operational");
+    console.log("✅ Bridge operational:", config.pauseFlags === 0);
     console.log("📊 Bridge token mint:", bridgeTokenMint.toString());
   });
 
@@ -129,6 +132,21 @@ describe("amm_integration_test", () => {
     console.log("🔒 Restricted mint:", restrictedMint.publicKey.toString());
   });
 
+  it("🏦 Setup: Register vault for the restricted mint", async () => {
+    console.log("🏦 Registering token vault...");
+
+    const tx = await program.methods
+      .registerVault({ custom: {} })
+      .accountsPartial({
+        restrictedTokenMint: restrictedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    console.log("✅ Token vault registered! TX:", tx);
+  });
+
   it("📦 Step 1: Wrap Token-2022 → Bridge Tokens", async () => {
     console.log("Converting restricted Token-2022 to bridge tokens...");
     
@@ -140,13 +158,26 @@ describe("amm_integration_test", () => {
       TOKEN_2022_PROGRAM_ID
     );
 
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      restrictedMint.publicKey,
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_vault"), restrictedMint.publicKey.toBuffer()],
+        program.programId
+      )[0],
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
     const wrapTx = await program.methods
       .wrapTokens(new anchor.BN(wrapAmount))
-      .accounts({
+      .accountsPartial({
         user: trader.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         userRestrictedTokenAccount: traderTokenAccount,
+        vaultTokenAccount,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -230,10 +261,13 @@ describe("amm_integration_test", () => {
 
     const unwrapTx = await program.methods
       .unwrapTokens(new anchor.BN(unwrapAmount))
-      .accounts({
+      .accountsPartial({
         user: trader.publicKey,
+        recipient: trader.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -89,17 +89,20 @@ describe("clean_devnet_production_test", () => {
     console.log("🔍 Verifying bridge system is operational...");
     
     const config = await program.account.bridgeConfig.fetch(bridgeConfig);
-    bridgeTokenMint = config.bridgeTokenMint;
+    [bridgeTokenMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bridge_token_mint"), restrictedMint.publicKey.toBuffer()],
+      program.programId
+    );
     
     console.log("📊 Bridge Status:");
-    console.log("- Active:", config.isActive);
+    console.log("- Active:", config.pauseFlags === 0);
     console.log("- Authority:", config.authority.toString());
     console.log("- Bridge Token Mint:", bridgeTokenMint.toString());
     console.log("- Approved Hook Programs:", config.approvedHookPrograms.length);
     
-    // Verify bridge token mint exists
+    // The per-mint bridge token mint is created when the vault is registered
     const mintInfo = await connection.getAccountInfo(bridgeTokenMint);
-    console.log("✅ Bridge token mint verified:", !!mintInfo);
+    console.log("ℹ️ Bridge token mint exists yet:", !!mintInfo);
   });
 
   it("🔧 Create Token2022 mint and setup", async () => {
@@ -174,6 +177,21 @@ describe("clean_devnet_production_test", () => {
     console.log("🔗 Funding TX:", fundTx);
   });
 
+  it("🏦 Register token vault", async () => {
+    console.log("🏦 Registering token vault...");
+
+    const tx = await program.methods
+      .registerVault({ custom: {} })
+      .accountsPartial({
+        restrictedTokenMint: restrictedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    console.log("✅ Token vault registered! TX:", tx);
+  });
+
   it("📦 Execute wrap operation (Lock Token2022 → Mint bridge tokens)", async () => {
     console.log("🔄 Testing token wrapping...");
     
@@ -190,13 +208,26 @@ describe("clean_devnet_production_test", () => {
     console.log("- User:", testUser.publicKey.toString());
     console.log("- Token2022 mint:", restrictedMint.publicKey.toString());
 
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      restrictedMint.publicKey,
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_vault"), restrictedMint.publicKey.toBuffer()],
+        program.programId
+      )[0],
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
     const wrapTx = await program.methods
       .wrapTokens(new anchor.BN(wrapAmount))
-      .accounts({
+      .accountsPartial({
         user: testUser.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         userRestrictedTokenAccount: userTokenAccount,
+        vaultTokenAccount,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -244,10 +275,13 @@ describe("clean_devnet_production_test", () => {
 
     const unwrapTx = await program.methods
       .unwrapTokens(new anchor.BN(unwrapAmount))
-      .accounts({
+      .accountsPartial({
         user: testUser.publicKey,
+        recipient: testUser.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -364,16 +398,16 @@ describe("clean_devnet_production_test", () => {
     console.log("📍 Program ID:", program.programId.toString());
     console.log("");
     console.log("🔧 BRIDGE SYSTEM:");
-    console.log("✓ Bridge Active:", config.isActive);
+    console.log("✓ Bridge Active:", config.pauseFlags === 0);
     console.log("✓ Bridge Authority:", config.authority.toString());
-    console.log("✓ Bridge Token Mint:", config.bridgeTokenMint.toString());
+    console.log("✓ Bridge Token Mint:", bridgeTokenMint.toString());
     console.log("✓ Approved Hook Programs:", config.approvedHookPrograms.length);
     console.log("");
     console.log("🏦 TOKEN VAULT:");
     console.log("✓ Test Vault Address:", tokenVault.toString());
     console.log("✓ Token2022 Mint:", vault.restrictedTokenMint.toString());
     console.log("✓ Tokens Currently Locked:", Number(vault.totalLocked.toString()) / (10 ** decimals));
-    console.log("✓ Vault→Bridge Mint Link:", vault.bridgeTokenMint.equals(config.bridgeTokenMint));
+    console.log("✓ Vault→Bridge Mint Link:", vault.bridgeTokenMint.equals(bridgeTokenMint));
     console.log("");
     console.log("📋 WHITELIST SYSTEM:");
     if (whitelistData) {
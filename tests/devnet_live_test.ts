@@ -45,6 +45,10 @@ describe("devnet_live_test", () => {
     [Buffer.from("bridge_config")],
     program.programId
   );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
 
   let bridgeTokenMint: PublicKey;
 
@@ -88,6 +92,10 @@ describe("devnet_live_test", () => {
     try {
       const tx = await program.methods
         .initializeBridge()
+        .accountsPartial({
+          bridgeProgram: program.programId,
+          programData,
+        })
         .rpc({
           commitment: "confirmed",
         });
@@ -99,7 +107,7 @@ describe("devnet_live_test", () => {
       const config = await program.account.bridgeConfig.fetch(bridgeConfig);
       console.log("📊 Bridge Config:", {
         authority: config.authority.toString(),
-        isActive: config.isActive,
+        paused: config.pauseFlags !== 0,
         programId: program.programId.toString(),
       });
 
@@ -109,7 +117,7 @@ describe("devnet_live_test", () => {
         const config = await program.account.bridgeConfig.fetch(bridgeConfig);
         console.log("📊 Existing Bridge Config:", {
           authority: config.authority.toString(),
-          isActive: config.isActive,
+          paused: config.pauseFlags !== 0,
         });
       } else {
         throw error;
@@ -117,36 +125,6 @@ describe("devnet_live_test", () => {
     }
   });
 
-  it("🪙 Tests bridge token mint creation on devnet", async () => {
-    console.log("🧪 Testing bridge token mint creation...");
-    
-    try {
-      const bridgeTokenKeypair = new Keypair();
-      bridgeTokenMint = bridgeTokenKeypair.publicKey;
-
-      const tx = await program.methods
-        .createBridgeTokenMint()
-        .accounts({
-          bridgeTokenMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([bridgeTokenKeypair])
-        .rpc({
-          commitment: "confirmed",
-        });
-
-      console.log("✅ Bridge token mint created on devnet! TX:", tx);
-      console.log("🔗 View on Solscan:", `https://solscan.io/tx/${tx}?cluster=devnet`);
-      console.log("🪙 Bridge Token Mint:", bridgeTokenMint.toString());
-
-    } catch (error) {
-      console.log("ℹ️ Using existing bridge token mint");
-      const config = await program.account.bridgeConfig.fetch(bridgeConfig);
-      bridgeTokenMint = config.bridgeTokenMint;
-      console.log("🪙 Existing Bridge Token Mint:", bridgeTokenMint.toString());
-    }
-  });
-
   it("🔧 Tests Token2022 creation and bridge flow on devnet", async () => {
     console.log("🧪 Testing complete bridge flow on devnet...");
     
@@ -178,6 +156,26 @@ describe("devnet_live_test", () => {
     console.log("✅ Token2022 mint created on devnet:", restrictedMint.publicKey.toString());
     console.log("🔗 View on Solscan:", `https://solscan.io/tx/${createTx}?cluster=devnet`);
 
+    // Register the vault and its bridge token mint
+    const registerTx = await program.methods
+      .registerVault({ custom: {} })
+      .accountsPartial({
+        restrictedTokenMint: restrictedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc({
+        commitment: "confirmed",
+      });
+
+    [bridgeTokenMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bridge_token_mint"), restrictedMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    console.log("✅ Token vault registered on devnet! TX:", registerTx);
+    console.log("🪙 Bridge Token Mint:", bridgeTokenMint.toString());
+
     // Fund user with tokens
     const amount = 1000 * (10 ** decimals);
     const userTokenAccount = getAssociatedTokenAddressSync(
@@ -219,13 +217,26 @@ describe("devnet_live_test", () => {
     console.log("📦 Testing wrap on devnet...");
     const wrapAmount = 500 * (10 ** decimals);
 
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      restrictedMint.publicKey,
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_vault"), restrictedMint.publicKey.toBuffer()],
+        program.programId
+      )[0],
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
     const wrapTx = await program.methods
       .wrapTokens(new anchor.BN(wrapAmount))
-      .accounts({
+      .accountsPartial({
         user: testUser.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         userRestrictedTokenAccount: userTokenAccount,
+        vaultTokenAccount,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -243,10 +254,13 @@ describe("devnet_live_test", () => {
 
     const unwrapTx = await program.methods
       .unwrapTokens(new anchor.BN(unwrapAmount))
-      .accounts({
+      .accountsPartial({
         user: testUser.publicKey,
+        recipient: testUser.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -272,8 +286,8 @@ describe("devnet_live_test", () => {
     console.log("📊 DEVNET DEPLOYMENT SUCCESS!");
     console.log("🌐 Network: Devnet");
     console.log("📍 Program ID:", program.programId.toString());
-    console.log("✓ Bridge Active:", config.isActive);
-    console.log("✓ Bridge Token Mint:", config.bridgeTokenMint.toString());
+    console.log("✓ Bridge Active:", config.pauseFlags === 0);
+    console.log("✓ Bridge Token Mint:", bridgeTokenMint.toString());
     console.log("✓ Tokens in Vault:", Number(vault.totalLocked.toString()) / (10 ** decimals));
     console.log("✓ Token2022 Mint:", restrictedMint.publicKey.toString());
     
@@ -0,0 +1,297 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TokenBridgeWorkspace } from "../target/types/token_bridge_workspace";
+import {
+  AccountMeta,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+  sendAndConfirmTransaction,
+  Keypair,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  ExtensionType,
+  createInitializeMintInstruction,
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+// Fixtures shared by the bridge test suites. Every suite runs against the
+// same singleton BridgeConfig, so whatever a suite changes there it restores
+// before it finishes.
+
+anchor.setProvider(anchor.AnchorProvider.env());
+
+export const program = anchor.workspace.tokenBridgeWorkspace as Program<TokenBridgeWorkspace>;
+export const provider = anchor.AnchorProvider.env();
+export const wallet = provider.wallet as anchor.Wallet;
+export const connection = provider.connection;
+
+export const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+// Derive PDAs
+export const [bridgeConfig] = PublicKey.findProgramAddressSync(
+  [Buffer.from("bridge_config")],
+  program.programId
+);
+export const [bridgeRoles] = PublicKey.findProgramAddressSync(
+  [Buffer.from("bridge_roles")],
+  program.programId
+);
+export const [adminMultisig] = PublicKey.findProgramAddressSync(
+  [Buffer.from("admin_multisig")],
+  program.programId
+);
+export const [programData] = PublicKey.findProgramAddressSync(
+  [program.programId.toBuffer()],
+  BPF_LOADER_UPGRADEABLE_ID
+);
+
+export const findTokenVault = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("token_vault"), mint.toBuffer()],
+    program.programId
+  )[0];
+
+export const findBridgeTokenMint = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("bridge_token_mint"), mint.toBuffer()],
+    program.programId
+  )[0];
+
+export const findBridgeTokenMetadata = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), findBridgeTokenMint(mint).toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+
+export const findFeeAccount = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_account"), findTokenVault(mint).toBuffer()],
+    program.programId
+  )[0];
+
+export const findUserQuota = (mint: PublicKey, user: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("user_quota"), findTokenVault(mint).toBuffer(), user.toBuffer()],
+    program.programId
+  )[0];
+
+export const findWhitelist = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("whitelist"), mint.toBuffer()],
+    program.programId
+  )[0];
+
+export const restrictedAta = (mint: PublicKey, owner: PublicKey) =>
+  getAssociatedTokenAddressSync(mint, owner, true, TOKEN_2022_PROGRAM_ID);
+
+export const bridgeAta = (mint: PublicKey, owner: PublicKey) =>
+  getAssociatedTokenAddressSync(findBridgeTokenMint(mint), owner, true, TOKEN_PROGRAM_ID);
+
+export const balanceOf = async (tokenAccount: PublicKey, tokenProgram: PublicKey) =>
+  Number((await getAccount(connection, tokenAccount, "confirmed", tokenProgram)).amount);
+
+export const sleep = (seconds: number) => new Promise(resolve => setTimeout(resolve, seconds * 1000));
+
+export const airdrop = async (...keypairs: Keypair[]) => {
+  try {
+    for (const keypair of keypairs) {
+      await connection.requestAirdrop(keypair.publicKey, 5 * anchor.web3.LAMPORTS_PER_SOL);
+    }
+    await sleep(3);
+    console.log("✅ Test accounts funded");
+  } catch (error) {
+    console.log("ℹ️ Airdrop unavailable, using existing balances");
+  }
+};
+
+// Only the upgrade authority can initialize; access_control.ts covers that.
+export const initializeBridgeIfNeeded = async () => {
+  if (await connection.getAccountInfo(bridgeConfig)) {
+    console.log("ℹ️ Bridge already initialized");
+    return;
+  }
+
+  await program.methods
+    .initializeBridge()
+    .accountsPartial({
+      bridgeProgram: program.programId,
+      programData,
+    })
+    .rpc();
+  console.log("✅ Bridge initialized");
+};
+
+// Creates a Token-2022 mint; `extensionIxs` run between account creation
+// and InitializeMint, as extensions require.
+export const createRestrictedMint = async (
+  options: {
+    decimals?: number;
+    extensions?: ExtensionType[];
+    extensionIxs?: (mint: PublicKey) => TransactionInstruction[];
+    freezeAuthority?: PublicKey;
+  } = {}
+): Promise<Keypair> => {
+  const mint = new Keypair();
+  const space = getMintLen(options.extensions ?? []);
+
+  const transaction = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: wallet.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space,
+      lamports: await connection.getMinimumBalanceForRentExemption(space),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    ...(options.extensionIxs ? options.extensionIxs(mint.publicKey) : []),
+    createInitializeMintInstruction(
+      mint.publicKey,
+      options.decimals ?? 6,
+      wallet.publicKey,
+      options.freezeAuthority ?? null,
+      TOKEN_2022_PROGRAM_ID,
+    )
+  );
+
+  await sendAndConfirmTransaction(connection, transaction, [wallet.payer, mint]);
+  return mint;
+};
+
+// Creates `owner`'s restricted token account and mints `amount` into it.
+export const fundUser = async (mint: PublicKey, owner: PublicKey, amount: number) => {
+  const transaction = new Transaction().add(
+    createAssociatedTokenAccountInstruction(
+      wallet.publicKey,
+      restrictedAta(mint, owner),
+      owner,
+      mint,
+      TOKEN_2022_PROGRAM_ID
+    ),
+    createMintToInstruction(
+      mint,
+      restrictedAta(mint, owner),
+      wallet.publicKey,
+      amount,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    )
+  );
+
+  await sendAndConfirmTransaction(connection, transaction, [wallet.payer]);
+};
+
+// Custom hook type by default: plain mints need no whitelist or attestation
+// accounts.
+export const registerVault = (
+  mint: PublicKey,
+  options: { hookType?: any; registrar?: Keypair } = {}
+) => {
+  const registrar = options.registrar ?? wallet.payer;
+
+  return program.methods
+    .registerVault(options.hookType ?? { custom: {} })
+    .accountsPartial({
+      registrar: registrar.publicKey,
+      restrictedTokenMint: mint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+    })
+    .signers([registrar])
+    .rpc();
+};
+
+export type HookAccounts = {
+  whitelist?: PublicKey;
+  kycAttestation?: PublicKey;
+  remainingAccounts?: AccountMeta[];
+};
+
+export const wrap = (mint: PublicKey, user: Keypair, amount: number, hook: HookAccounts = {}) =>
+  program.methods
+    .wrapTokens(new anchor.BN(amount))
+    .accountsPartial({
+      user: user.publicKey,
+      restrictedTokenMint: mint,
+      userRestrictedTokenAccount: restrictedAta(mint, user.publicKey),
+      vaultTokenAccount: restrictedAta(mint, findTokenVault(mint)),
+      bridgeTokenMint: findBridgeTokenMint(mint),
+      whitelist: hook.whitelist ?? null,
+      kycAttestation: hook.kycAttestation ?? null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+    })
+    .remainingAccounts(hook.remainingAccounts ?? [])
+    .signers([user])
+    .rpc();
+
+// Pays out to `recipient`, the signer by default
+export const unwrap = (
+  mint: PublicKey,
+  user: Keypair,
+  amount: number,
+  hook: HookAccounts = {},
+  recipient: PublicKey = user.publicKey
+) =>
+  program.methods
+    .unwrapTokens(new anchor.BN(amount))
+    .accountsPartial({
+      user: user.publicKey,
+      recipient,
+      restrictedTokenMint: mint,
+      recipientTokenAccount: restrictedAta(mint, recipient),
+      bridgeTokenMint: findBridgeTokenMint(mint),
+      whitelist: hook.whitelist ?? null,
+      kycAttestation: hook.kycAttestation ?? null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+    })
+    .remainingAccounts(hook.remainingAccounts ?? [])
+    .signers([user])
+    .rpc();
+
+// Events are emitted through a self-CPI (`emit_cpi!`), so they are read
+// back from the transaction's inner instructions.
+export const fetchEvents = async (signature: string) => {
+  await connection.confirmTransaction(signature, "confirmed");
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = tx.transaction.message.staticAccountKeys;
+  const events = [];
+
+  for (const inner of tx.meta.innerInstructions) {
+    for (const ix of inner.instructions) {
+      if (!accountKeys[ix.programIdIndex].equals(program.programId)) continue;
+
+      // 8-byte event CPI tag, then the Anchor event
+      const data = anchor.utils.bytes.bs58.decode(ix.data).slice(8);
+      const event = program.coder.events.decode(anchor.utils.bytes.base64.encode(Buffer.from(data)));
+      if (event) events.push(event);
+    }
+  }
+  return events;
+};
+
+export const findEvent = (events: any[], name: string) =>
+  events.find((event) => event.name.toLowerCase() === name.toLowerCase());
+
+export const expectFailure = async (promise: Promise<unknown>, code: string) => {
+  try {
+    await promise;
+  } catch (error) {
+    expect(error.toString()).to.include(code);
+    console.log(`✅ Rejected with ${code}`);
+    return;
+  }
+  expect.fail(`Expected the transaction to fail with ${code}`);
+};
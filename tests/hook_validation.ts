@@ -0,0 +1,287 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TestTransferHook } from "../target/types/test_transfer_hook";
+import {
+  AccountMeta,
+  PublicKey,
+  Keypair,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  createInitializeTransferHookInstruction,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  program,
+  wallet,
+  findTokenVault,
+  findWhitelist,
+  restrictedAta,
+  balanceOf,
+  airdrop,
+  initializeBridgeIfNeeded,
+  createRestrictedMint,
+  fundUser,
+  registerVault,
+  wrap,
+  unwrap,
+  expectFailure,
+} from "./helpers";
+
+describe("hook_validation_test", () => {
+  // Test keypairs
+  const testUser = new Keypair();
+  // Records every transfer in a per-mint counter, its one extra account
+  const hookProgram = anchor.workspace.testTransferHook as Program<TestTransferHook>;
+
+  const findCounter = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("counter"), mint.toBuffer()],
+      hookProgram.programId
+    )[0];
+  const findExtraAccountMetaList = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), mint.toBuffer()],
+      hookProgram.programId
+    )[0];
+
+  // What Token-2022 needs to invoke the test hook for `mint`
+  const hookRemainingAccounts = (mint: PublicKey): AccountMeta[] => [
+    { pubkey: findCounter(mint), isSigner: false, isWritable: true },
+    { pubkey: hookProgram.programId, isSigner: false, isWritable: false },
+    { pubkey: findExtraAccountMetaList(mint), isSigner: false, isWritable: false },
+  ];
+
+  const createHookedMint = (hookProgramId: PublicKey) =>
+    createRestrictedMint({
+      extensions: [ExtensionType.TransferHook],
+      extensionIxs: (mint) => [
+        createInitializeTransferHookInstruction(
+          mint,
+          wallet.publicKey,
+          hookProgramId,
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ],
+    });
+
+  const createTestHookMint = async () => {
+    const mint = await createHookedMint(hookProgram.programId);
+    await hookProgram.methods
+      .initializeExtraAccountMetaList()
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    return mint;
+  };
+
+  before(async () => {
+    console.log("🔧 Setting up hook validation test...");
+
+    await airdrop(testUser);
+    await initializeBridgeIfNeeded();
+
+    await program.methods.addApprovedHookProgram(hookProgram.programId).rpc();
+    console.log("✅ Approved test hook program:", hookProgram.programId.toString());
+  });
+
+  after(async () => {
+    await program.methods.removeApprovedHookProgram(hookProgram.programId).rpc();
+    console.log("🧹 Removed test hook program");
+  });
+
+  it("Rejects mints hooked to an unapproved program or to the bridge itself", async () => {
+    console.log("🪝 Registering mints with unusable hooks...");
+
+    const unapproved = await createHookedMint(new Keypair().publicKey);
+    await expectFailure(registerVault(unapproved.publicKey), "UnapprovedHookProgram");
+
+    const selfHooked = await createHookedMint(program.programId);
+    await expectFailure(registerVault(selfHooked.publicKey), "BridgeHookNotSupported");
+  });
+
+  it("Requires the hook's extra accounts when wrapping a hooked mint", async () => {
+    console.log("🪝 Wrapping a hooked mint without its extra accounts...");
+
+    const mint = await createTestHookMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.hookProgramId.equals(hookProgram.programId)).to.be.true;
+
+    await expectFailure(wrap(mint.publicKey, testUser, 1_000_000), "MissingTransferHookAccounts");
+
+    const counter = await hookProgram.account.transferCounter.fetch(findCounter(mint.publicKey));
+    expect(counter.transfers.toNumber()).to.equal(0);
+  });
+
+  it("Runs the mint's transfer hook when wrapping and unwrapping", async () => {
+    console.log("🪝 Wrapping and unwrapping through the test hook...");
+
+    const mint = await createTestHookMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const userAccount = restrictedAta(mint.publicKey, testUser.publicKey);
+    const remainingAccounts = hookRemainingAccounts(mint.publicKey);
+
+    await wrap(mint.publicKey, testUser, 600_000, { remainingAccounts });
+    expect(await balanceOf(userAccount, TOKEN_2022_PROGRAM_ID)).to.equal(400_000);
+
+    let counter = await hookProgram.account.transferCounter.fetch(findCounter(mint.publicKey));
+    expect(counter.transfers.toNumber()).to.equal(1);
+    expect(counter.amount.toNumber()).to.equal(600_000);
+    console.log("✅ Hook ran on the transfer into the vault");
+
+    // The vault's transfer out goes through the hook as well
+    await expectFailure(unwrap(mint.publicKey, testUser, 200_000), "MissingTransferHookAccounts");
+    await unwrap(mint.publicKey, testUser, 200_000, { remainingAccounts });
+
+    const received = (await balanceOf(userAccount, TOKEN_2022_PROGRAM_ID)) - 400_000;
+    expect(received).to.be.greaterThan(0);
+
+    counter = await hookProgram.account.transferCounter.fetch(findCounter(mint.publicKey));
+    expect(counter.transfers.toNumber()).to.equal(2);
+    expect(counter.amount.toNumber()).to.equal(600_000 + received);
+    console.log("✅ Hook ran on the transfer out of the vault");
+  });
+
+  it("Enforces the mint whitelist for whitelist vaults", async () => {
+    console.log("📋 Wrapping through a whitelist vault...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey, { hookType: { whitelist: {} } });
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await program.methods
+      .initializeWhitelist()
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+
+    const whitelist = findWhitelist(mint.publicKey);
+    await expectFailure(wrap(mint.publicKey, testUser, 100_000), "WhitelistAccountRequired");
+    await expectFailure(wrap(mint.publicKey, testUser, 100_000, { whitelist }), "SenderNotWhitelisted");
+
+    await program.methods
+      .addToWhitelist(testUser.publicKey)
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    await wrap(mint.publicKey, testUser, 100_000, { whitelist });
+
+    const vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.totalLocked.toNumber()).to.equal(100_000);
+    console.log("✅ Whitelisted user wrapped");
+  });
+
+  it("Requires a KYC attestation for KYC vaults", async () => {
+    console.log("🪪 Wrapping through a KYC vault without an attestation...");
+
+    const mint = await createHookedMint(hookProgram.programId);
+    await registerVault(mint.publicKey, { hookType: { kyc: {} } });
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    await expectFailure(wrap(mint.publicKey, testUser, 100_000), "KycAttestationMissing");
+
+    // An attestation must be the hook program's PDA for this mint and user
+    await expectFailure(
+      wrap(mint.publicKey, testUser, 100_000, { kycAttestation: new Keypair().publicKey }),
+      "KycAttestationMissing"
+    );
+  });
+
+  it("Blocks wraps while the vault's hook is suspended", async () => {
+    console.log("⏸️ Suspending a vault hook...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const setHookActive = (isActive: boolean) =>
+      program.methods
+        .updateHookMetadata({ custom: {} }, isActive)
+        .accountsPartial({ tokenVault: findTokenVault(mint.publicKey) })
+        .rpc();
+
+    await setHookActive(false);
+    await expectFailure(wrap(mint.publicKey, testUser, 100_000), "HookSuspended");
+
+    await setHookActive(true);
+    await wrap(mint.publicKey, testUser, 100_000);
+    console.log("✅ Wrap succeeded once the hook was reactivated");
+  });
+
+  it("Enforces the mint whitelist on unwrap", async () => {
+    console.log("📋 Unwrapping after removal from the whitelist...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey, { hookType: { whitelist: {} } });
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await program.methods
+      .initializeWhitelist()
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    await program.methods
+      .addToWhitelist(testUser.publicKey)
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+
+    const whitelist = findWhitelist(mint.publicKey);
+    await wrap(mint.publicKey, testUser, 500_000, { whitelist });
+
+    await expectFailure(unwrap(mint.publicKey, testUser, 100_000), "WhitelistAccountRequired");
+
+    await program.methods
+      .removeFromWhitelist(testUser.publicKey)
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    await expectFailure(unwrap(mint.publicKey, testUser, 100_000, { whitelist }), "RecipientNotWhitelisted");
+
+    await program.methods
+      .addToWhitelist(testUser.publicKey)
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    await unwrap(mint.publicKey, testUser, 100_000, { whitelist });
+
+    const vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.totalLocked.toNumber()).to.equal(400_000);
+    console.log("✅ Unwrap allowed only while whitelisted");
+  });
+
+
+  it("Checks the recipient, not the sender, when unwrapping to a third party", async () => {
+    console.log("📮 Unwrapping into another wallet...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey, { hookType: { whitelist: {} } });
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await program.methods
+      .initializeWhitelist()
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    await program.methods
+      .addToWhitelist(testUser.publicKey)
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+
+    const whitelist = findWhitelist(mint.publicKey);
+    await wrap(mint.publicKey, testUser, 500_000, { whitelist });
+
+    const recipient = new Keypair().publicKey;
+    const unwrapTo = (amount: number) => unwrap(mint.publicKey, testUser, amount, { whitelist }, recipient);
+
+    await expectFailure(unwrapTo(100_000), "RecipientNotWhitelisted");
+
+    await program.methods
+      .addToWhitelist(recipient)
+      .accounts({ mint: mint.publicKey })
+      .rpc();
+    await unwrapTo(100_000);
+
+    expect(await balanceOf(restrictedAta(mint.publicKey, recipient), TOKEN_2022_PROGRAM_ID)).to.equal(100_000);
+    expect(await balanceOf(restrictedAta(mint.publicKey, testUser.publicKey), TOKEN_2022_PROGRAM_ID)).to.equal(500_000);
+
+    const vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.totalLocked.toNumber()).to.equal(400_000);
+    console.log("✅ Restricted tokens redeemed into the recipient's wallet");
+  });
+});
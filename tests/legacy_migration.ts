@@ -0,0 +1,107 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  program,
+  wallet,
+  connection,
+  bridgeConfig,
+  findTokenVault,
+  restrictedAta,
+  airdrop,
+  initializeBridgeIfNeeded,
+  createRestrictedMint,
+  registerVault,
+  expectFailure,
+} from "./helpers";
+
+// Accounts in the shared-mint layout cannot be created by the current
+// program, so this suite covers how the migration instructions treat a
+// deployment that is already current.
+describe("legacy_migration_test", () => {
+  // Test keypairs
+  const holder = new Keypair();
+
+  let restrictedMint: Keypair;
+
+  before(async () => {
+    console.log("🔧 Setting up legacy migration test...");
+
+    await airdrop(holder);
+    await initializeBridgeIfNeeded();
+
+    restrictedMint = await createRestrictedMint();
+    await registerVault(restrictedMint.publicKey);
+  });
+
+  it("Records no legacy state on a fresh deployment", async () => {
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.legacyBridgeTokenMint).to.be.null;
+
+    const vault = await program.account.tokenVault.fetch(findTokenVault(restrictedMint.publicKey));
+    expect(vault.legacyLocked.toNumber()).to.equal(0);
+    console.log("✅ No legacy mint or legacy locked tokens recorded");
+  });
+
+  it("Does not migrate a bridge config twice", async () => {
+    await expectFailure(
+      program.methods
+        .migrateBridgeConfig()
+        .accountsPartial({ authority: wallet.publicKey })
+        .rpc(),
+      "already in use"
+    );
+  });
+
+  it("Only migrates vaults in the legacy layout", async () => {
+    await expectFailure(
+      program.methods
+        .migrateLegacyVault()
+        .accountsPartial({
+          payer: holder.publicKey,
+          restrictedTokenMint: restrictedMint.publicKey,
+        })
+        .signers([holder])
+        .rpc(),
+      "NotLegacyAccount"
+    );
+  });
+
+  it("Does not release tokens locked through the per-mint bridge", async () => {
+    console.log("🪙 Creating an unrelated classic SPL mint...");
+
+    const otherMint = await createMint(connection, wallet.payer, wallet.publicKey, null, 9);
+    const holderAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      otherMint,
+      holder.publicKey
+    );
+
+    await expectFailure(
+      program.methods
+        .redeemLegacyTokens(new anchor.BN(1))
+        .accountsPartial({
+          holder: holder.publicKey,
+          restrictedTokenMint: restrictedMint.publicKey,
+          holderRestrictedTokenAccount: restrictedAta(restrictedMint.publicKey, holder.publicKey),
+          vaultTokenAccount: restrictedAta(restrictedMint.publicKey, findTokenVault(restrictedMint.publicKey)),
+          legacyBridgeTokenMint: otherMint,
+          holderLegacyTokenAccount: holderAccount.address,
+          whitelist: null,
+          kycAttestation: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([holder])
+        .rpc(),
+      "InsufficientLockedTokens"
+    );
+  });
+});
@@ -0,0 +1,437 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+  Keypair,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  program,
+  wallet,
+  connection,
+  bridgeConfig,
+  bridgeRoles,
+  adminMultisig,
+  findTokenVault,
+  findBridgeTokenMint,
+  findUserQuota,
+  balanceOf,
+  sleep,
+  airdrop,
+  initializeBridgeIfNeeded,
+  createRestrictedMint,
+  registerVault,
+  expectFailure,
+} from "./helpers";
+
+// Installs a 2-of-3 admin multisig and removes it again in `after`, so the
+// other suites keep direct admin access.
+describe("multisig_governance_test", () => {
+  // Test keypairs
+  const signerA = new Keypair();
+  const signerB = new Keypair();
+  const outsider = new Keypair();
+
+  const findProposal = (index: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_proposal"), adminMultisig.toBuffer(), index.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  // Creates a proposal from `proposer` (its first approval) and returns its PDA
+  const createProposal = async (action: any, proposer: Keypair = wallet.payer) => {
+    const multisig = await program.account.adminMultisig.fetch(adminMultisig);
+    const proposal = findProposal(multisig.proposalCount);
+
+    await program.methods
+      .createAdminProposal(action)
+      .accountsPartial({
+        proposer: proposer.publicKey,
+        proposal,
+      })
+      .signers([proposer])
+      .rpc();
+
+    return proposal;
+  };
+
+  const approveProposal = (proposal: PublicKey, approver: Keypair) =>
+    program.methods
+      .approveAdminProposal()
+      .accountsPartial({
+        approver: approver.publicKey,
+        proposal,
+      })
+      .signers([approver])
+      .rpc();
+
+  const executeProposal = (
+    proposal: PublicKey,
+    targets: { tokenVault?: PublicKey; hookMetadata?: PublicKey; bridgeRoles?: PublicKey } = {}
+  ) =>
+    program.methods
+      .executeAdminProposal()
+      .accountsPartial({
+        proposal,
+        tokenVault: targets.tokenVault ?? null,
+        hookMetadata: targets.hookMetadata ?? null,
+        bridgeRoles: targets.bridgeRoles ?? null,
+      })
+      .rpc();
+
+  const cancelProposal = (proposal: PublicKey, guardian: Keypair) =>
+    program.methods
+      .cancelAdminProposal()
+      .accountsPartial({
+        guardian: guardian.publicKey,
+        proposal,
+      })
+      .signers([guardian])
+      .rpc();
+
+  // Timelock changes are proposals too; with no delay in force yet this one
+  // executes as soon as signerA approves it
+  const setTimelock = async (delay: number) => {
+    const proposal = await createProposal({ setTimelockConfig: { delay: new anchor.BN(delay), exemptions: 0 } });
+    await approveProposal(proposal, signerA);
+    await executeProposal(proposal);
+  };
+
+  // Creates a plain Token-2022 mint and registers its vault
+  const createVault = async (): Promise<PublicKey> => {
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    return mint.publicKey;
+  };
+
+  before(async () => {
+    console.log("🔧 Setting up multisig governance test...");
+
+    await airdrop(signerA, signerB, outsider);
+    await initializeBridgeIfNeeded();
+
+    await program.methods
+      .initializeAdminMultisig([wallet.publicKey, signerA.publicKey, signerB.publicKey], 2)
+      .rpc();
+    console.log("✅ 2-of-3 admin multisig installed");
+  });
+
+  after(async () => {
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    if (config.multisig === null) return;
+
+    const delay = config.timelockDelay.toNumber();
+    if (delay > 0) {
+      const reset = await createProposal({ setTimelockConfig: { delay: new anchor.BN(0), exemptions: 0 } });
+      await approveProposal(reset, signerA);
+      await sleep(delay + 2);
+      await executeProposal(reset);
+    }
+
+    const removal = await createProposal({ removeMultisig: {} });
+    await approveProposal(removal, signerA);
+    await executeProposal(removal);
+    console.log("🧹 Admin multisig removed");
+  });
+
+  it("Requires a proposal for critical actions once the multisig exists", async () => {
+    console.log("🔒 Calling a critical instruction directly...");
+
+    await expectFailure(
+      program.methods.addApprovedHookProgram(new Keypair().publicKey).rpc(),
+      "MultisigRequired"
+    );
+  });
+
+  it("Requires a proposal for fee changes once the multisig exists", async () => {
+    console.log("💸 Changing fees directly...");
+
+    const tokenVault = findTokenVault(await createVault());
+
+    await expectFailure(program.methods.setFeeConfig(10, 10, 100).rpc(), "MultisigRequired");
+    await expectFailure(
+      program.methods.setVaultFees(25, null).accountsPartial({ tokenVault }).rpc(),
+      "MultisigRequired"
+    );
+  });
+
+  it("Executes a proposal once the threshold is reached", async () => {
+    console.log("🗳️ Approving a hook program through the multisig...");
+
+    const hookProgramId = new Keypair().publicKey;
+    const proposal = await createProposal({ addApprovedHookProgram: { hookProgramId } });
+
+    await expectFailure(executeProposal(proposal), "ThresholdNotReached");
+    await expectFailure(approveProposal(proposal, wallet.payer), "AlreadyApproved");
+    await expectFailure(approveProposal(proposal, outsider), "NotMultisigSigner");
+
+    await approveProposal(proposal, signerA);
+    await executeProposal(proposal);
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.approvedHookPrograms.some((id) => id.equals(hookProgramId))).to.be.true;
+
+    const executed = await program.account.adminProposal.fetch(proposal);
+    expect(executed.executed).to.be.true;
+    await expectFailure(executeProposal(proposal), "ProposalAlreadyExecuted");
+    console.log("✅ Hook approved by 2 of 3 signers");
+  });
+
+  it("Invalidates open proposals when the signer set changes", async () => {
+    console.log("👥 Rotating the multisig signers...");
+
+    const stale = await createProposal({ addApprovedHookProgram: { hookProgramId: new Keypair().publicKey } });
+
+    const rotation = await createProposal({
+      updateMultisig: { signers: [wallet.publicKey, signerA.publicKey, signerB.publicKey], threshold: 2 },
+    });
+    await approveProposal(rotation, signerB);
+    await executeProposal(rotation);
+
+    await expectFailure(approveProposal(stale, signerA), "ProposalStale");
+  });
+
+  it("Lets only granted guardians and signers cancel, and never a key rotation", async () => {
+    console.log("🛑 Checking who may cancel a proposal...");
+
+    const GUARDIAN = 1 << 5;
+    const pending = await createProposal({ addApprovedHookProgram: { hookProgramId: new Keypair().publicKey } });
+    await expectFailure(cancelProposal(pending, outsider), "MissingRole");
+
+    // An explicit GUARDIAN grant is enough
+    const grant = await createProposal({ grantRole: { member: outsider.publicKey, roles: GUARDIAN } });
+    await approveProposal(grant, signerA);
+    await executeProposal(grant, { bridgeRoles });
+    await cancelProposal(pending, outsider);
+    expect((await program.account.adminProposal.fetch(pending)).cancelled).to.be.true;
+
+    // Authority rotations can only be stopped by withholding approvals
+    const rotation = await createProposal({ proposeAuthority: { newAuthority: new Keypair().publicKey } });
+    await expectFailure(cancelProposal(rotation, signerA), "ProposalNotCancellable");
+
+    // The bridge authority's implicit roles do not count once it is not a signer
+    const withoutAuthority = await createProposal({
+      updateMultisig: { signers: [signerA.publicKey, signerB.publicKey], threshold: 2 },
+    });
+    await approveProposal(withoutAuthority, signerA);
+    await executeProposal(withoutAuthority);
+
+    const open = await createProposal({ addApprovedHookProgram: { hookProgramId: new Keypair().publicKey } }, signerA);
+    await expectFailure(cancelProposal(open, wallet.payer), "MissingRole");
+    console.log("✅ Authority alone cannot veto the multisig");
+
+    const restore = await createProposal(
+      { updateMultisig: { signers: [wallet.publicKey, signerA.publicKey, signerB.publicKey], threshold: 2 } },
+      signerA
+    );
+    await approveProposal(restore, signerB);
+    await executeProposal(restore);
+
+    const revoke = await createProposal({ revokeRole: { member: outsider.publicKey, roles: GUARDIAN } });
+    await approveProposal(revoke, signerA);
+    await executeProposal(revoke, { bridgeRoles });
+  });
+
+  it("Holds approved proposals until the timelock elapses", async () => {
+    console.log("⏳ Enabling a 5 second governance timelock...");
+
+    const delay = 5;
+    await expectFailure(program.methods.setTimelockConfig(new anchor.BN(delay), 0).rpc(), "MultisigRequired");
+    await setTimelock(delay);
+    let config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.timelockDelay.toNumber()).to.equal(delay);
+
+    const hookProgramId = new Keypair().publicKey;
+    const proposal = await createProposal({ addApprovedHookProgram: { hookProgramId } });
+    await approveProposal(proposal, signerA);
+
+    const queued = await program.account.adminProposal.fetch(proposal);
+    expect(queued.eta).to.not.be.null;
+    await expectFailure(executeProposal(proposal), "TimelockNotElapsed");
+
+    await sleep(delay + 2);
+    await executeProposal(proposal);
+    config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.approvedHookPrograms.some((id) => id.equals(hookProgramId))).to.be.true;
+    console.log("✅ Proposal executed after the delay");
+
+    // Any multisig signer can stop a queued proposal
+    const cancelled = await createProposal({ addApprovedHookProgram: { hookProgramId: new Keypair().publicKey } });
+    await approveProposal(cancelled, signerA);
+    await cancelProposal(cancelled, signerB);
+    await sleep(delay + 2);
+    await expectFailure(executeProposal(cancelled), "ProposalCancelled");
+
+    // Turning the timelock off is itself timelocked
+
+    const reset = await createProposal({ setTimelockConfig: { delay: new anchor.BN(0), exemptions: 0 } });
+    await approveProposal(reset, signerB);
+    await sleep(delay + 2);
+    await executeProposal(reset);
+
+    config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.timelockDelay.toNumber()).to.equal(0);
+    console.log("✅ Timelock disabled through a proposal");
+  });
+
+  it("Reconciles vault surplus only through an approved proposal", async () => {
+    console.log("🎁 Reconciling a vault surplus under the multisig...");
+
+    const mint = await createVault();
+    const tokenVault = findTokenVault(mint);
+    const vaultTokenAccount = getAssociatedTokenAddressSync(mint, tokenVault, true, TOKEN_2022_PROGRAM_ID);
+    const bridgeTokenMint = findBridgeTokenMint(mint);
+    const recipientTokenAccount = getAssociatedTokenAddressSync(bridgeTokenMint, wallet.publicKey, false, TOKEN_PROGRAM_ID);
+
+    const transaction = new Transaction().add(
+      createMintToInstruction(mint, vaultTokenAccount, wallet.publicKey, 250_000, [], TOKEN_2022_PROGRAM_ID),
+      createAssociatedTokenAccountInstruction(
+        wallet.publicKey,
+        recipientTokenAccount,
+        wallet.publicKey,
+        bridgeTokenMint,
+        TOKEN_PROGRAM_ID
+      )
+    );
+    await sendAndConfirmTransaction(connection, transaction, [wallet.payer]);
+
+    const reconcile = (governance: { adminMultisig: PublicKey; proposal: PublicKey } | null) =>
+      program.methods
+        .reconcileVaultSurplus({ mintToRecipient: {} })
+        .accountsPartial({
+          restrictedTokenMint: mint,
+          vaultTokenAccount,
+          bridgeTokenMint,
+          treasuryTokenAccount: null,
+          recipientTokenAccount,
+          adminMultisig: governance ? governance.adminMultisig : null,
+          proposal: governance ? governance.proposal : null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+    await expectFailure(reconcile(null), "MultisigRequired");
+
+    const action = {
+      reconcileVaultSurplus: { tokenVault, mode: { mintToRecipient: {} }, destination: recipientTokenAccount },
+    };
+    const proposal = await createProposal(action);
+    await expectFailure(reconcile({ adminMultisig, proposal }), "ThresholdNotReached");
+
+    await approveProposal(proposal, signerA);
+    // The sweep needs accounts execute_admin_proposal does not take
+    await expectFailure(executeProposal(proposal), "ProposalNotExecutable");
+
+    await reconcile({ adminMultisig, proposal });
+
+    const vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.totalLocked.toNumber()).to.equal(250_000);
+    expect(await balanceOf(recipientTokenAccount, TOKEN_PROGRAM_ID)).to.equal(250_000);
+    expect((await program.account.adminProposal.fetch(proposal)).executed).to.be.true;
+
+    // A proposal is consumed once
+    await expectFailure(reconcile({ adminMultisig, proposal }), "ProposalAlreadyExecuted");
+    console.log("✅ Surplus minted through the approved proposal");
+  });
+
+
+  it("Only removes the multisig once the timelock is off", async () => {
+    console.log("🗑️ Removing the multisig under a timelock...");
+
+    await setTimelock(2);
+    const removal = await createProposal({ removeMultisig: {} });
+    await approveProposal(removal, signerA);
+    await sleep(4);
+    await expectFailure(executeProposal(removal), "TimelockActive");
+
+    const reset = await createProposal({ setTimelockConfig: { delay: new anchor.BN(0), exemptions: 0 } });
+    await approveProposal(reset, signerA);
+    await sleep(4);
+    await executeProposal(reset);
+
+    // Proposals made under the delay are stale once it changes
+    await expectFailure(executeProposal(removal), "ProposalStale");
+
+    const retry = await createProposal({ removeMultisig: {} });
+    await approveProposal(retry, signerA);
+    await executeProposal(retry);
+
+    let config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.multisig).to.be.null;
+    console.log("✅ Multisig removed, direct admin access restored");
+
+    // Reinstalling keeps the proposal numbering and leaves old proposals stale
+    const previous = await program.account.adminMultisig.fetch(adminMultisig);
+    await program.methods
+      .initializeAdminMultisig([wallet.publicKey, signerA.publicKey, signerB.publicKey], 2)
+      .rpc();
+    const reinstalled = await program.account.adminMultisig.fetch(adminMultisig);
+    expect(reinstalled.proposalCount.toNumber()).to.equal(previous.proposalCount.toNumber());
+    expect(reinstalled.signerSetVersion.toNumber()).to.equal(previous.signerSetVersion.toNumber() + 1);
+
+    await expectFailure(
+      program.methods.initializeAdminMultisig([wallet.publicKey], 1).rpc(),
+      "InvalidMultisigConfig"
+    );
+  });
+
+  it("Applies timelocked user quota overrides through an approved proposal", async () => {
+    console.log("🎟️ Overriding a user quota under the timelock...");
+
+    const mint = await createVault();
+    const tokenVault = findTokenVault(mint);
+    const user = new Keypair().publicKey;
+    const userQuota = findUserQuota(mint, user);
+
+    const delay = 3;
+    await setTimelock(delay);
+
+    const setUserQuota = (governance: { adminMultisig: PublicKey; proposal: PublicKey } | null) =>
+      program.methods
+        .setUserQuota(user, new anchor.BN(1_000), null)
+        .accountsPartial({
+          tokenVault,
+          adminMultisig: governance ? governance.adminMultisig : null,
+          proposal: governance ? governance.proposal : null,
+        })
+        .rpc();
+
+    await expectFailure(setUserQuota(null), "TimelockRequired");
+
+    const proposal = await createProposal({
+      setUserQuota: { tokenVault, user, wrapQuota: new anchor.BN(1_000), unwrapQuota: null },
+    });
+    await approveProposal(proposal, signerA);
+
+    // The quota account may not exist yet, so the override is applied by set_user_quota
+    await expectFailure(executeProposal(proposal), "ProposalNotExecutable");
+    await expectFailure(setUserQuota({ adminMultisig, proposal }), "TimelockNotElapsed");
+
+    await sleep(delay + 2);
+    await setUserQuota({ adminMultisig, proposal });
+
+    const quota = await program.account.userQuota.fetch(userQuota);
+    expect(quota.user.equals(user)).to.be.true;
+    expect(quota.wrapQuota.toNumber()).to.equal(1_000);
+    expect(quota.unwrapQuota).to.be.null;
+    expect((await program.account.adminProposal.fetch(proposal)).executed).to.be.true;
+    console.log("✅ Override applied after the delay");
+
+    const reset = await createProposal({ setTimelockConfig: { delay: new anchor.BN(0), exemptions: 0 } });
+    await approveProposal(reset, signerA);
+    await sleep(delay + 2);
+    await executeProposal(reset);
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.timelockDelay.toNumber()).to.equal(0);
+  });
+});
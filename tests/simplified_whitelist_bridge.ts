@@ -32,6 +32,10 @@ describe("simple_whitelist_test", () => {
     [Buffer.from("bridge_config")],
     program.programId
   );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
 
   const [whitelist] = PublicKey.findProgramAddressSync(
     [Buffer.from("whitelist"), restrictedMint.publicKey.toBuffer()],
@@ -59,6 +63,10 @@ describe("simple_whitelist_test", () => {
     try {
       const tx = await program.methods
         .initializeBridge()
+        .accountsPartial({
+          bridgeProgram: program.programId,
+          programData,
+        })
         .rpc();
       console.log("✅ Bridge initialized! TX:", tx);
     } catch (error) {
@@ -66,29 +74,6 @@ describe("simple_whitelist_test", () => {
     }
   });
 
-  it("Creates bridge token mint (prerequisite)", async () => {
-    console.log("🪙 Creating bridge token mint...");
-    
-    try {
-      const bridgeTokenKeypair = new Keypair();
-      bridgeTokenMint = bridgeTokenKeypair.publicKey;
-
-      const tx = await program.methods
-        .createBridgeTokenMint()
-        .accounts({
-          bridgeTokenMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([bridgeTokenKeypair])
-        .rpc();
-      console.log("✅ Bridge token mint created! TX:", tx);
-    } catch (error) {
-      console.log("ℹ️ Using existing bridge token mint");
-      const config = await program.account.bridgeConfig.fetch(bridgeConfig);
-      bridgeTokenMint = config.bridgeTokenMint;
-    }
-  });
-
   it("Creates Token2022 mint", async () => {
     console.log("🔧 Creating Token2022 mint...");
     
@@ -113,6 +98,27 @@ describe("simple_whitelist_test", () => {
     console.log("✅ Token2022 mint created:", restrictedMint.publicKey.toString());
   });
 
+  it("Registers whitelist vault (prerequisite)", async () => {
+    console.log("🏦 Registering token vault...");
+
+    const tx = await program.methods
+      .registerVault({ whitelist: {} })
+      .accountsPartial({
+        restrictedTokenMint: restrictedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    [bridgeTokenMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bridge_token_mint"), restrictedMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    console.log("✅ Token vault registered! TX:", tx);
+    console.log("🪙 Bridge Token Mint:", bridgeTokenMint.toString());
+  });
+
   it("Initializes simple whitelist", async () => {
     console.log("📋 Initializing simple whitelist...");
     
@@ -203,8 +209,8 @@ describe("simple_whitelist_test", () => {
     const whitelistData = await program.account.simpleWhitelist.fetch(whitelist);
     
     console.log("📊 Final State Summary:");
-    console.log("✓ Bridge Active:", config.isActive);
-    console.log("✓ Bridge Token Mint:", config.bridgeTokenMint.toString());
+    console.log("✓ Bridge Active:", config.pauseFlags === 0);
+    console.log("✓ Bridge Token Mint:", bridgeTokenMint.toString());
     console.log("✓ Token2022 Mint:", restrictedMint.publicKey.toString());
     console.log("✓ Whitelist Authority:", whitelistData.authority.toString());
     console.log("✓ Whitelist Users Count:", whitelistData.users.length);
@@ -36,6 +36,10 @@ describe("bridge_only_test", () => {
     [Buffer.from("bridge_config")],
     program.programId
   );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
 
   let bridgeTokenMint: PublicKey;
 
@@ -61,6 +65,10 @@ describe("bridge_only_test", () => {
     try {
       const tx = await program.methods
         .initializeBridge()
+        .accountsPartial({
+          bridgeProgram: program.programId,
+          programData,
+        })
         .rpc();
 
       console.log("✅ Bridge initialized! TX:", tx);
@@ -71,7 +79,7 @@ describe("bridge_only_test", () => {
         console.log("ℹ️  Bridge already initialized");
         console.log("📊 Bridge Config:", {
           authority: config.authority.toString(),
-          isActive: config.isActive,
+          paused: config.pauseFlags !== 0,
         });
       } catch (fetchError) {
         console.error("❌ Bridge initialization failed:", error.message);
@@ -80,25 +88,6 @@ describe("bridge_only_test", () => {
     }
   });
 
-  it("Creates bridge token mint", async () => {
-    console.log("🪙 Creating bridge token mint...");
-    
-    const bridgeTokenKeypair = new Keypair();
-    bridgeTokenMint = bridgeTokenKeypair.publicKey;
-
-    const tx = await program.methods
-      .createBridgeTokenMint()
-      .accounts({
-        bridgeTokenMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([bridgeTokenKeypair])
-      .rpc();
-
-    console.log("✅ Bridge token mint created! TX:", tx);
-    console.log("🪙 Bridge Token Mint:", bridgeTokenMint.toString());
-  });
-
   it("Creates Token2022 mint and funds user", async () => {
     console.log("🔧 Creating Token2022 mint...");
     
@@ -154,6 +143,27 @@ describe("bridge_only_test", () => {
     console.log("✅ Test user funded with", amount / (10 ** decimals), "tokens");
   });
 
+  it("Registers vault for Token2022 mint", async () => {
+    console.log("🏦 Registering token vault...");
+
+    const tx = await program.methods
+      .registerVault({ custom: {} })
+      .accountsPartial({
+        restrictedTokenMint: restrictedMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    [bridgeTokenMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bridge_token_mint"), restrictedMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    console.log("✅ Token vault registered! TX:", tx);
+    console.log("🪙 Bridge Token Mint:", bridgeTokenMint.toString());
+  });
+
   it("Tests wrap functionality", async () => {
     console.log("📦 Testing token wrapping...");
     
@@ -165,13 +175,26 @@ describe("bridge_only_test", () => {
       TOKEN_2022_PROGRAM_ID
     );
 
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      restrictedMint.publicKey,
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_vault"), restrictedMint.publicKey.toBuffer()],
+        program.programId
+      )[0],
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
     const tx = await program.methods
       .wrapTokens(new anchor.BN(wrapAmount))
-      .accounts({
+      .accountsPartial({
         user: testUser.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         userRestrictedTokenAccount: userTokenAccount,
+        vaultTokenAccount,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -201,10 +224,13 @@ describe("bridge_only_test", () => {
 
     const tx = await program.methods
       .unwrapTokens(new anchor.BN(unwrapAmount))
-      .accounts({
+      .accountsPartial({
         user: testUser.publicKey,
+        recipient: testUser.publicKey,
         restrictedTokenMint: restrictedMint.publicKey,
         bridgeTokenMint,
+        whitelist: null,
+        kycAttestation: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
       })
@@ -251,9 +277,9 @@ describe("bridge_only_test", () => {
     const vault = await program.account.tokenVault.fetch(tokenVault);
     
     console.log("📊 Final State Summary:");
-    console.log("✓ Bridge Active:", config.isActive);
+    console.log("✓ Bridge Active:", config.pauseFlags === 0);
     console.log("✓ Bridge Authority:", config.authority.toString());
-    console.log("✓ Bridge Token Mint:", config.bridgeTokenMint.toString());
+    console.log("✓ Bridge Token Mint:", bridgeTokenMint.toString());
     console.log("✓ Approved Hook Programs:", config.approvedHookPrograms.length);
     console.log("✓ Token2022 Mint:", restrictedMint.publicKey.toString());
     console.log("✓ Tokens Locked in Vault:", Number(vault.totalLocked.toString()) / (10 ** decimals));
@@ -261,7 +287,7 @@ describe("bridge_only_test", () => {
     console.log("🎯 BRIDGE-ONLY TEST COMPLETE!");
     console.log("🏆 All core bridge functionality verified:");
     console.log("  ✅ Bridge initialization");
-    console.log("  ✅ Bridge token mint creation");
+    console.log("  ✅ Vault registration");
     console.log("  ✅ Token2022 support");
     console.log("  ✅ Token wrapping (Lock Token2022 → Mint bridge tokens)");
     console.log("  ✅ Token unwrapping (Burn bridge tokens → Unlock Token2022)");
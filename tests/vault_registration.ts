@@ -0,0 +1,302 @@
+import {
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+  Keypair,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  ExtensionType,
+  createInitializeTransferFeeConfigInstruction,
+  createInitializeInterestBearingMintInstruction,
+  createInitializeNonTransferableMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeMetadataPointerInstruction,
+  tokenMetadataInitializeWithRentTransfer,
+  tokenMetadataUpdateFieldWithRentTransfer,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+  getMint,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  program,
+  wallet,
+  connection,
+  bridgeConfig,
+  findTokenVault,
+  findBridgeTokenMint,
+  findBridgeTokenMetadata,
+  airdrop,
+  initializeBridgeIfNeeded,
+  createRestrictedMint,
+  registerVault,
+  expectFailure,
+} from "./helpers";
+
+describe("vault_registration_test", () => {
+  // ExtensionFlags bits recorded in TokenVault.extensionsBitmap
+  const TRANSFER_FEE = 1 << 1;
+  const INTEREST_BEARING = 1 << 8;
+
+  // Test keypairs
+  const outsider = new Keypair();
+
+  // Reads name, symbol and uri from a Metaplex metadata account:
+  // key (1), update authority (32), mint (32), then borsh strings.
+  const fetchBridgeTokenMetadata = async (mint: PublicKey) => {
+    const info = await connection.getAccountInfo(findBridgeTokenMetadata(mint));
+    let offset = 1 + 32 + 32;
+    const readString = () => {
+      const length = info.data.readUInt32LE(offset);
+      const value = info.data.subarray(offset + 4, offset + 4 + length).toString("utf8");
+      offset += 4 + length;
+      return value.replace(/\0+$/, "");
+    };
+
+    return {
+      updateAuthority: new PublicKey(info.data.subarray(1, 33)),
+      name: readString(),
+      symbol: readString(),
+      uri: readString(),
+    };
+  };
+
+  before(async () => {
+    console.log("🔧 Setting up vault registration test...");
+
+    await airdrop(outsider);
+    await initializeBridgeIfNeeded();
+  });
+
+  it("Derives a separate bridge token mint per restricted mint", async () => {
+    console.log("🪙 Registering two restricted mints...");
+
+    const first = await createRestrictedMint({ decimals: 9 });
+    const second = await createRestrictedMint({ decimals: 9 });
+    await registerVault(first.publicKey);
+    await registerVault(second.publicKey);
+
+    const firstVault = await program.account.tokenVault.fetch(findTokenVault(first.publicKey));
+    const secondVault = await program.account.tokenVault.fetch(findTokenVault(second.publicKey));
+
+    expect(firstVault.bridgeTokenMint.equals(findBridgeTokenMint(first.publicKey))).to.be.true;
+    expect(secondVault.bridgeTokenMint.equals(findBridgeTokenMint(second.publicKey))).to.be.true;
+    expect(firstVault.bridgeTokenMint.equals(secondVault.bridgeTokenMint)).to.be.false;
+
+    const bridgeMint = await getMint(connection, firstVault.bridgeTokenMint, "confirmed", TOKEN_PROGRAM_ID);
+    expect(bridgeMint.mintAuthority.equals(bridgeConfig)).to.be.true;
+    expect(Number(bridgeMint.supply)).to.equal(0);
+
+    console.log("✅ Bridge mints:", firstVault.bridgeTokenMint.toString(), secondVault.bridgeTokenMint.toString());
+  });
+
+  it("Creates bridge mints under the classic SPL Token program only", async () => {
+    console.log("🪙 Registering with Token-2022 as the bridge token program...");
+
+    const mint = await createRestrictedMint();
+    await expectFailure(
+      program.methods
+        .registerVault({ custom: {} })
+        .accountsPartial({
+          restrictedTokenMint: mint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc(),
+      "InvalidProgramId"
+    );
+  });
+
+  it("Mirrors the restricted mint's decimals on the bridge token mint", async () => {
+    console.log("🔢 Registering mints with non-default decimals...");
+
+    for (const decimals of [0, 6]) {
+      const mint = await createRestrictedMint({ decimals });
+      await registerVault(mint.publicKey);
+
+      const bridgeMint = await getMint(connection, findBridgeTokenMint(mint.publicKey), "confirmed", TOKEN_PROGRAM_ID);
+      expect(bridgeMint.decimals).to.equal(decimals);
+      console.log(`✅ ${decimals}-decimal mint bridged with ${bridgeMint.decimals} decimals`);
+    }
+  });
+
+  it("Records the restricted mint's extensions on the vault", async () => {
+    console.log("🧩 Registering mints with Token-2022 extensions...");
+
+    const plain = await createRestrictedMint({ decimals: 9 });
+    await registerVault(plain.publicKey);
+    let vault = await program.account.tokenVault.fetch(findTokenVault(plain.publicKey));
+    expect(vault.extensionsBitmap.toNumber()).to.equal(0);
+
+    const extended = await createRestrictedMint({
+      decimals: 9,
+      extensions: [ExtensionType.TransferFeeConfig, ExtensionType.InterestBearingConfig],
+      extensionIxs: (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          wallet.publicKey,
+          wallet.publicKey,
+          50,
+          BigInt(1_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeInterestBearingMintInstruction(
+          mint,
+          wallet.publicKey,
+          100,
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ],
+    });
+    await registerVault(extended.publicKey);
+    vault = await program.account.tokenVault.fetch(findTokenVault(extended.publicKey));
+    expect(vault.extensionsBitmap.toNumber()).to.equal(TRANSFER_FEE | INTEREST_BEARING);
+    expect(vault.hookProgramId).to.be.null;
+
+    console.log("✅ Extensions bitmap:", vault.extensionsBitmap.toString(2));
+  });
+
+  it("Rejects mints the bridge cannot custody", async () => {
+    console.log("🚫 Registering unbridgeable mints...");
+
+    const nonTransferable = await createRestrictedMint({
+      decimals: 9,
+      extensions: [ExtensionType.NonTransferable],
+      extensionIxs: (mint) => [createInitializeNonTransferableMintInstruction(mint, TOKEN_2022_PROGRAM_ID)],
+    });
+    await expectFailure(registerVault(nonTransferable.publicKey), "NonTransferableToken");
+
+    const delegated = await createRestrictedMint({
+      decimals: 9,
+      extensions: [ExtensionType.PermanentDelegate],
+      extensionIxs: (mint) => [createInitializePermanentDelegateInstruction(mint, wallet.publicKey, TOKEN_2022_PROGRAM_ID)],
+    });
+    await expectFailure(registerVault(delegated.publicKey), "PermanentDelegateNotAllowed");
+
+    // A freeze authority lets the issuer lock the vault's tokens
+    const freezable = await createRestrictedMint({ decimals: 9, freezeAuthority: wallet.publicKey });
+    await expectFailure(registerVault(freezable.publicKey), "FreezeAuthorityNotAllowed");
+  });
+
+  it("Refuses to register a mint twice", async () => {
+    console.log("🔁 Re-registering an existing vault...");
+
+    const mint = await createRestrictedMint({ decimals: 9 });
+    await registerVault(mint.publicKey);
+    await expectFailure(registerVault(mint.publicKey), "TokenVaultAlreadyExists");
+  });
+
+  it("Registers even when the vault token account was created up front", async () => {
+    console.log("🏃 Pre-creating the vault's associated token account...");
+
+    const mint = await createRestrictedMint({ decimals: 9 });
+    const tokenVault = findTokenVault(mint.publicKey);
+    const vaultTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, tokenVault, true, TOKEN_2022_PROGRAM_ID);
+
+    const transaction = new Transaction().add(
+      createAssociatedTokenAccountInstruction(
+        wallet.publicKey,
+        vaultTokenAccount,
+        tokenVault,
+        mint.publicKey,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await sendAndConfirmTransaction(connection, transaction, [wallet.payer]);
+
+    await registerVault(mint.publicKey);
+
+    const vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.vaultTokenAccount.equals(vaultTokenAccount)).to.be.true;
+    console.log("✅ Vault registered over the existing token account");
+  });
+
+  it("Requires the vault manager role unless registration is permissionless", async () => {
+    console.log("🔐 Registering from an account without roles...");
+
+    const mint = await createRestrictedMint({ decimals: 9 });
+    await expectFailure(registerVault(mint.publicKey, { registrar: outsider }), "MissingRole");
+
+    await program.methods.setPermissionlessVaultRegistration(true).rpc();
+    try {
+      await registerVault(mint.publicKey, { registrar: outsider });
+      const vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+      expect(vault.restrictedTokenMint.equals(mint.publicKey)).to.be.true;
+      console.log("✅ Permissionless registration accepted");
+    } finally {
+      await program.methods.setPermissionlessVaultRegistration(false).rpc();
+    }
+  });
+
+  it("Creates Metaplex metadata mirrored from the restricted mint", async () => {
+    console.log("🏷️ Registering a mint with Token-2022 metadata...");
+
+    const mint = await createRestrictedMint({
+      decimals: 9,
+      extensions: [ExtensionType.MetadataPointer],
+      extensionIxs: (mint) => [
+        createInitializeMetadataPointerInstruction(mint, wallet.publicKey, mint, TOKEN_2022_PROGRAM_ID),
+      ],
+    });
+    await tokenMetadataInitializeWithRentTransfer(
+      connection,
+      wallet.payer,
+      mint.publicKey,
+      wallet.publicKey,
+      wallet.payer,
+      "Restricted Gold",
+      "RGLD",
+      "https://example.com/rgld.json",
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await registerVault(mint.publicKey);
+
+    let metadata = await fetchBridgeTokenMetadata(mint.publicKey);
+    expect(metadata.updateAuthority.equals(bridgeConfig)).to.be.true;
+    expect(metadata.name).to.equal("Wrapped RGLD");
+    expect(metadata.symbol).to.equal("wRGLD");
+    expect(metadata.uri).to.equal("https://example.com/rgld.json");
+    console.log("✅ Bridge metadata:", metadata.name, metadata.symbol);
+
+    console.log("🔄 Refreshing after the issuer renames the token...");
+    await tokenMetadataUpdateFieldWithRentTransfer(
+      connection,
+      wallet.payer,
+      mint.publicKey,
+      wallet.payer,
+      "symbol",
+      "RGOLD",
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await program.methods
+      .refreshBridgeTokenMetadata()
+      .accountsPartial({
+        tokenVault: findTokenVault(mint.publicKey),
+        restrictedTokenMint: mint.publicKey,
+        bridgeTokenMint: findBridgeTokenMint(mint.publicKey),
+        bridgeTokenMetadata: findBridgeTokenMetadata(mint.publicKey),
+      })
+      .rpc();
+
+    metadata = await fetchBridgeTokenMetadata(mint.publicKey);
+    expect(metadata.name).to.equal("Wrapped RGOLD");
+    expect(metadata.symbol).to.equal("wRGOLD");
+  });
+
+  it("Names mints without metadata after their address", async () => {
+    const mint = await createRestrictedMint({ decimals: 9 });
+    await registerVault(mint.publicKey);
+
+    const prefix = mint.publicKey.toBase58().slice(0, 6);
+    const metadata = await fetchBridgeTokenMetadata(mint.publicKey);
+    expect(metadata.name).to.equal(`Wrapped ${prefix}`);
+    expect(metadata.symbol).to.equal(`w${prefix}`);
+    expect(metadata.uri).to.equal("");
+  });
+});
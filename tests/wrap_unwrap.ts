@@ -0,0 +1,668 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+  Keypair,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  ExtensionType,
+  createInitializeTransferFeeConfigInstruction,
+  createInitializePermanentDelegateInstruction,
+  createBurnCheckedInstruction,
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  program,
+  wallet,
+  connection,
+  bridgeConfig,
+  findTokenVault,
+  findBridgeTokenMint,
+  findFeeAccount,
+  findUserQuota,
+  restrictedAta,
+  bridgeAta,
+  balanceOf,
+  airdrop,
+  initializeBridgeIfNeeded,
+  createRestrictedMint,
+  fundUser,
+  registerVault,
+  wrap,
+  unwrap,
+  fetchEvents,
+  findEvent,
+  expectFailure,
+} from "./helpers";
+
+describe("wrap_unwrap_test", () => {
+  // PauseFlags bits
+  const PAUSE_WRAP = 1 << 0;
+  const PAUSE_UNWRAP = 1 << 1;
+
+  // Test keypairs
+  const testUser = new Keypair();
+
+  const donateToVault = async (mint: PublicKey, amount: number) => {
+    const transaction = new Transaction().add(
+      createMintToInstruction(
+        mint,
+        restrictedAta(mint, findTokenVault(mint)),
+        wallet.publicKey,
+        amount,
+        [],
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+
+    await sendAndConfirmTransaction(connection, transaction, [wallet.payer]);
+  };
+
+  const reconcileSurplus = (
+    mint: PublicKey,
+    mode: any,
+    destinations: { treasuryTokenAccount?: PublicKey; recipientTokenAccount?: PublicKey }
+  ) =>
+    program.methods
+      .reconcileVaultSurplus(mode)
+      .accountsPartial({
+        restrictedTokenMint: mint,
+        vaultTokenAccount: restrictedAta(mint, findTokenVault(mint)),
+        bridgeTokenMint: findBridgeTokenMint(mint),
+        treasuryTokenAccount: destinations.treasuryTokenAccount ?? null,
+        recipientTokenAccount: destinations.recipientTokenAccount ?? null,
+        adminMultisig: null,
+        proposal: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+  before(async () => {
+    console.log("🔧 Setting up wrap/unwrap test...");
+
+    await airdrop(testUser);
+    await initializeBridgeIfNeeded();
+  });
+
+  it("Mints and releases net of Token-2022 transfer fees", async () => {
+    console.log("💸 Wrapping a mint with a 1% transfer fee...");
+
+    const feeBps = 100;
+    const mint = await createRestrictedMint({
+      extensions: [ExtensionType.TransferFeeConfig],
+      extensionIxs: (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          wallet.publicKey,
+          wallet.publicKey,
+          feeBps,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ],
+    });
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    await wrap(mint.publicKey, testUser, 1_000_000);
+
+    const vaultAccount = restrictedAta(mint.publicKey, findTokenVault(mint.publicKey));
+    expect(await balanceOf(vaultAccount, TOKEN_2022_PROGRAM_ID)).to.equal(990_000);
+    expect(await balanceOf(bridgeAta(mint.publicKey, testUser.publicKey), TOKEN_PROGRAM_ID)).to.equal(990_000);
+
+    let vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.totalLocked.toNumber()).to.equal(990_000);
+    console.log("✅ Minted 990,000 bridge tokens for 1,000,000 sent");
+
+    await unwrap(mint.publicKey, testUser, 500_000);
+
+    expect(await balanceOf(restrictedAta(mint.publicKey, testUser.publicKey), TOKEN_2022_PROGRAM_ID)).to.equal(495_000);
+    expect(await balanceOf(bridgeAta(mint.publicKey, testUser.publicKey), TOKEN_PROGRAM_ID)).to.equal(490_000);
+
+    vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.totalLocked.toNumber()).to.equal(490_000);
+    console.log("✅ Burned 500,000 bridge tokens and released 495,000 after the fee");
+  });
+
+  it("Emits Wrapped and Unwrapped events", async () => {
+    console.log("📣 Checking wrap and unwrap events...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const wrapped = findEvent(await fetchEvents(await wrap(mint.publicKey, testUser, 600_000)), "Wrapped");
+    expect(wrapped).to.not.be.undefined;
+    expect(wrapped.data.user.equals(testUser.publicKey)).to.be.true;
+    expect(wrapped.data.restrictedTokenMint.equals(mint.publicKey)).to.be.true;
+    expect(wrapped.data.amount.toNumber()).to.equal(600_000);
+    expect(wrapped.data.transferFee.toNumber()).to.equal(0);
+    expect(wrapped.data.minted.toNumber()).to.equal(600_000);
+    expect(wrapped.data.vaultTotalLocked.toNumber()).to.equal(600_000);
+
+    const unwrapped = findEvent(await fetchEvents(await unwrap(mint.publicKey, testUser, 200_000)), "Unwrapped");
+    expect(unwrapped).to.not.be.undefined;
+    expect(unwrapped.data.recipient.equals(testUser.publicKey)).to.be.true;
+    expect(unwrapped.data.burned.toNumber()).to.equal(200_000);
+    expect(unwrapped.data.received.toNumber()).to.equal(200_000);
+    expect(unwrapped.data.vaultTotalLocked.toNumber()).to.equal(400_000);
+
+    console.log("✅ Events decoded:", wrapped.name, unwrapped.name);
+  });
+
+  it("Pauses wraps and unwraps independently, bridge-wide and per vault", async () => {
+    console.log("⏸️ Pausing wraps only...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await wrap(mint.publicKey, testUser, 500_000);
+
+    await program.methods.pause(PAUSE_WRAP, 7).rpc();
+    try {
+      let config = await program.account.bridgeConfig.fetch(bridgeConfig);
+      expect(config.pauseFlags).to.equal(PAUSE_WRAP);
+      expect(config.pauseReason).to.equal(7);
+
+      await expectFailure(wrap(mint.publicKey, testUser, 100_000), "WrapPaused");
+      await unwrap(mint.publicKey, testUser, 100_000);
+      console.log("✅ Unwraps keep working while wraps are paused");
+
+      // Pausing is additive and idempotent
+      await program.methods.pause(PAUSE_UNWRAP, 7).rpc();
+      await program.methods.pause(PAUSE_UNWRAP, 7).rpc();
+      config = await program.account.bridgeConfig.fetch(bridgeConfig);
+      expect(config.pauseFlags).to.equal(PAUSE_WRAP | PAUSE_UNWRAP);
+      await expectFailure(unwrap(mint.publicKey, testUser, 100_000), "UnwrapPaused");
+
+      await expectFailure(program.methods.pause(0, 7).rpc(), "InvalidPauseFlags");
+    } finally {
+      await program.methods.unpause(PAUSE_WRAP | PAUSE_UNWRAP).rpc();
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.pauseFlags).to.equal(0);
+    expect(config.pauseReason).to.equal(0);
+
+    console.log("⏸️ Pausing unwraps on one vault...");
+    const tokenVault = findTokenVault(mint.publicKey);
+    await program.methods
+      .pauseVault(PAUSE_UNWRAP, 3)
+      .accountsPartial({ tokenVault })
+      .rpc();
+
+    await expectFailure(unwrap(mint.publicKey, testUser, 100_000), "UnwrapPaused");
+    await wrap(mint.publicKey, testUser, 100_000);
+
+    await program.methods
+      .unpauseVault(PAUSE_UNWRAP)
+      .accountsPartial({ tokenVault })
+      .rpc();
+    await unwrap(mint.publicKey, testUser, 100_000);
+
+    const vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.pauseFlags).to.equal(0);
+    console.log("✅ Vault pause flags cleared");
+  });
+
+  it("Keeps the live bridge mint when a vault is registered again", async () => {
+    console.log("🔁 Re-registering a vault with outstanding bridge tokens...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await wrap(mint.publicKey, testUser, 300_000);
+
+    await expectFailure(registerVault(mint.publicKey), "TokenVaultAlreadyExists");
+
+    const vault = await program.account.tokenVault.fetch(findTokenVault(mint.publicKey));
+    expect(vault.bridgeTokenMint.equals(findBridgeTokenMint(mint.publicKey))).to.be.true;
+    expect(vault.totalLocked.toNumber()).to.equal(300_000);
+    expect(await balanceOf(bridgeAta(mint.publicKey, testUser.publicKey), TOKEN_PROGRAM_ID)).to.equal(300_000);
+    console.log("✅ Vault and bridge mint left untouched");
+  });
+
+  it("Verifies that a vault fully backs its bridge tokens", async () => {
+    console.log("🧾 Verifying vault reserves...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await wrap(mint.publicKey, testUser, 700_000);
+    await unwrap(mint.publicKey, testUser, 200_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const signature = await program.methods
+      .verifyReserves()
+      .accountsPartial({
+        tokenVault,
+        vaultTokenAccount: restrictedAta(mint.publicKey, tokenVault),
+        bridgeTokenMint: findBridgeTokenMint(mint.publicKey),
+      })
+      .rpc();
+
+    const verified = findEvent(await fetchEvents(signature), "ReservesVerified");
+    expect(verified.data.vaultBalance.toNumber()).to.equal(500_000);
+    expect(verified.data.totalLocked.toNumber()).to.equal(500_000);
+    expect(verified.data.bridgeSupply.toNumber()).to.equal(500_000);
+    expect(verified.data.fullyBacked).to.be.true;
+    expect(verified.data.safeMode).to.be.false;
+
+    const vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.pauseFlags).to.equal(0);
+    console.log("✅ Vault fully backed, wraps still open");
+  });
+
+  it("Pauses wraps when the vault no longer backs its bridge tokens", async () => {
+    console.log("🕳️ Draining a vault through the mint's permanent delegate...");
+
+    const ALLOW_PERMANENT_DELEGATE = 1 << 0;
+    const RESERVE_SHORTFALL_REASON = 0xffff;
+
+    await program.methods.setAdmissionOverrides(ALLOW_PERMANENT_DELEGATE).rpc();
+    try {
+      const mint = await createRestrictedMint({
+        extensions: [ExtensionType.PermanentDelegate],
+        extensionIxs: (mint) => [
+          createInitializePermanentDelegateInstruction(mint, wallet.publicKey, TOKEN_2022_PROGRAM_ID),
+        ],
+      });
+      await registerVault(mint.publicKey);
+      await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+      await wrap(mint.publicKey, testUser, 500_000);
+
+      const tokenVault = findTokenVault(mint.publicKey);
+      const vaultTokenAccount = restrictedAta(mint.publicKey, tokenVault);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          createBurnCheckedInstruction(
+            vaultTokenAccount,
+            mint.publicKey,
+            wallet.publicKey,
+            100_000,
+            6,
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [wallet.payer]
+      );
+
+      const signature = await program.methods
+        .verifyReserves()
+        .accountsPartial({
+          tokenVault,
+          vaultTokenAccount,
+          bridgeTokenMint: findBridgeTokenMint(mint.publicKey),
+        })
+        .rpc();
+
+      const verified = findEvent(await fetchEvents(signature), "ReservesVerified");
+      expect(verified.data.vaultBalance.toNumber()).to.equal(400_000);
+      expect(verified.data.totalLocked.toNumber()).to.equal(500_000);
+      expect(verified.data.bridgeSupply.toNumber()).to.equal(500_000);
+      expect(verified.data.fullyBacked).to.be.false;
+      expect(verified.data.safeMode).to.be.true;
+
+      let vault = await program.account.tokenVault.fetch(tokenVault);
+      expect(vault.pauseFlags).to.equal(PAUSE_WRAP);
+      expect(vault.pauseReason).to.equal(RESERVE_SHORTFALL_REASON);
+      console.log("✅ Shortfall detected, vault is unwrap-only");
+
+      await expectFailure(wrap(mint.publicKey, testUser, 100_000), "WrapPaused");
+      await unwrap(mint.publicKey, testUser, 100_000);
+
+      await program.methods
+        .unpauseVault(PAUSE_WRAP)
+        .accountsPartial({ tokenVault })
+        .rpc();
+      vault = await program.account.tokenVault.fetch(tokenVault);
+      expect(vault.pauseFlags).to.equal(0);
+      expect(vault.pauseReason).to.equal(0);
+      console.log("✅ Vault manager lifted the pause");
+    } finally {
+      await program.methods.setAdmissionOverrides(0).rpc();
+    }
+  });
+
+  it("Reconciles tokens sent straight to the vault", async () => {
+    console.log("🎁 Donating restricted tokens to a vault...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+    await wrap(mint.publicKey, testUser, 500_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const userBridgeAccount = bridgeAta(mint.publicKey, testUser.publicKey);
+
+    await expectFailure(
+      reconcileSurplus(mint.publicKey, { mintToRecipient: {} }, { recipientTokenAccount: userBridgeAccount }),
+      "NoVaultSurplus"
+    );
+
+    await donateToVault(mint.publicKey, 200_000);
+    await expectFailure(reconcileSurplus(mint.publicKey, { mintToRecipient: {} }, {}), "MissingSurplusDestination");
+
+    await reconcileSurplus(mint.publicKey, { mintToRecipient: {} }, { recipientTokenAccount: userBridgeAccount });
+
+    let vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.totalLocked.toNumber()).to.equal(700_000);
+    expect(await balanceOf(userBridgeAccount, TOKEN_PROGRAM_ID)).to.equal(700_000);
+    console.log("✅ Surplus minted as bridge tokens");
+
+    console.log("🧹 Sweeping a second donation to the treasury...");
+    await program.methods.setTreasury(wallet.publicKey).rpc();
+    const transaction = new Transaction().add(
+      createAssociatedTokenAccountInstruction(
+        wallet.publicKey,
+        restrictedAta(mint.publicKey, wallet.publicKey),
+        wallet.publicKey,
+        mint.publicKey,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await sendAndConfirmTransaction(connection, transaction, [wallet.payer]);
+
+    await donateToVault(mint.publicKey, 100_000);
+    await reconcileSurplus(
+      mint.publicKey,
+      { sweepToTreasury: {} },
+      { treasuryTokenAccount: restrictedAta(mint.publicKey, wallet.publicKey) }
+    );
+
+    vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.totalLocked.toNumber()).to.equal(700_000);
+    expect(await balanceOf(restrictedAta(mint.publicKey, tokenVault), TOKEN_2022_PROGRAM_ID)).to.equal(700_000);
+    expect(await balanceOf(restrictedAta(mint.publicKey, wallet.publicKey), TOKEN_2022_PROGRAM_ID)).to.equal(100_000);
+    console.log("✅ Surplus swept, locked tokens untouched");
+  });
+
+
+  it("Charges bridge fees and pays them out to the treasury", async () => {
+    console.log("💸 Wrapping and unwrapping with bridge fees...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const feeAccount = findFeeAccount(mint.publicKey);
+    const treasuryBridgeAccount = bridgeAta(mint.publicKey, wallet.publicKey);
+
+    await expectFailure(program.methods.setFeeConfig(200, 100, 100).rpc(), "FeeTooHigh");
+
+    // 1% to wrap by default, 2% to unwrap from this vault
+    await program.methods.setFeeConfig(100, 50, 500).rpc();
+    try {
+      await expectFailure(
+        program.methods.setVaultFees(600, null).accountsPartial({ tokenVault }).rpc(),
+        "FeeTooHigh"
+      );
+      await program.methods.setVaultFees(null, 200).accountsPartial({ tokenVault }).rpc();
+
+      await wrap(mint.publicKey, testUser, 1_000_000);
+      expect(await balanceOf(bridgeAta(mint.publicKey, testUser.publicKey), TOKEN_PROGRAM_ID)).to.equal(990_000);
+      expect(await balanceOf(feeAccount, TOKEN_PROGRAM_ID)).to.equal(10_000);
+
+      await unwrap(mint.publicKey, testUser, 100_000);
+      expect(await balanceOf(restrictedAta(mint.publicKey, testUser.publicKey), TOKEN_2022_PROGRAM_ID)).to.equal(98_000);
+      expect(await balanceOf(feeAccount, TOKEN_PROGRAM_ID)).to.equal(12_000);
+
+      // Accrued fees stay backed by the vault
+      const vault = await program.account.tokenVault.fetch(tokenVault);
+      expect(vault.totalLocked.toNumber()).to.equal(902_000);
+      console.log("✅ Fees accrued in bridge tokens");
+
+      console.log("🏦 Withdrawing fees to the treasury...");
+      await program.methods.setTreasury(wallet.publicKey).rpc();
+      const transaction = new Transaction().add(
+        createAssociatedTokenAccountInstruction(
+          wallet.publicKey,
+          treasuryBridgeAccount,
+          wallet.publicKey,
+          findBridgeTokenMint(mint.publicKey),
+          TOKEN_PROGRAM_ID
+        )
+      );
+      await sendAndConfirmTransaction(connection, transaction, [wallet.payer]);
+
+      const withdraw = (amount: number) =>
+        program.methods
+          .withdrawFees(new anchor.BN(amount))
+          .accountsPartial({
+            tokenVault,
+            bridgeTokenMint: findBridgeTokenMint(mint.publicKey),
+            feeAccount,
+            destination: treasuryBridgeAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+
+      await expectFailure(withdraw(12_001), "InvalidBridgeTokenAmount");
+      await withdraw(12_000);
+
+      expect(await balanceOf(feeAccount, TOKEN_PROGRAM_ID)).to.equal(0);
+      expect(await balanceOf(treasuryBridgeAccount, TOKEN_PROGRAM_ID)).to.equal(12_000);
+      console.log("✅ Fees withdrawn to the treasury");
+    } finally {
+      await program.methods.setFeeConfig(0, 0, 0).rpc();
+    }
+  });
+
+
+  it("Enforces per-vault amount limits and the locked cap", async () => {
+    console.log("📏 Wrapping against vault limits...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_100_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const setLimits = (limits: any) =>
+      program.methods.setVaultLimits(limits).accountsPartial({ tokenVault }).rpc();
+
+    await expectFailure(
+      setLimits({
+        maxTotalLocked: new anchor.BN(0),
+        minWrapAmount: new anchor.BN(20_000),
+        maxWrapAmount: new anchor.BN(10_000),
+        minUnwrapAmount: new anchor.BN(0),
+        maxUnwrapAmount: new anchor.BN(0),
+      }),
+      "InvalidVaultLimits"
+    );
+
+    await setLimits({
+      maxTotalLocked: new anchor.BN(1_000_000),
+      minWrapAmount: new anchor.BN(10_000),
+      maxWrapAmount: new anchor.BN(600_000),
+      minUnwrapAmount: new anchor.BN(5_000),
+      maxUnwrapAmount: new anchor.BN(300_000),
+    });
+
+    await expectFailure(wrap(mint.publicKey, testUser, 5_000), "AmountBelowMinimum");
+    await expectFailure(wrap(mint.publicKey, testUser, 700_000), "AmountAboveMaximum");
+
+    await wrap(mint.publicKey, testUser, 600_000);
+    await wrap(mint.publicKey, testUser, 400_000);
+    await expectFailure(wrap(mint.publicKey, testUser, 10_000), "VaultCapExceeded");
+
+    await expectFailure(unwrap(mint.publicKey, testUser, 1_000), "AmountBelowMinimum");
+    await expectFailure(unwrap(mint.publicKey, testUser, 400_000), "AmountAboveMaximum");
+    await unwrap(mint.publicKey, testUser, 300_000);
+
+    const vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.totalLocked.toNumber()).to.equal(700_000);
+    console.log("✅ Limits enforced on both directions");
+  });
+
+
+  it("Rate limits wraps and unwraps per vault", async () => {
+    console.log("🚰 Draining the vault rate limit buckets...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const setRateLimits = (wrapCapacity: number, unwrapCapacity: number) =>
+      program.methods
+        .setVaultRateLimits(
+          { capacity: new anchor.BN(wrapCapacity), refillPerSecond: new anchor.BN(1) },
+          { capacity: new anchor.BN(unwrapCapacity), refillPerSecond: new anchor.BN(1) }
+        )
+        .accountsPartial({ tokenVault })
+        .rpc();
+
+    await setRateLimits(500_000, 200_000);
+
+    await wrap(mint.publicKey, testUser, 400_000);
+    await expectFailure(wrap(mint.publicKey, testUser, 200_000), "RateLimitExceeded");
+
+    await unwrap(mint.publicKey, testUser, 150_000);
+    await expectFailure(unwrap(mint.publicKey, testUser, 100_000), "RateLimitExceeded");
+
+    const vault = await program.account.tokenVault.fetch(tokenVault);
+    expect(vault.wrapRateLimit.available.toNumber()).to.be.lessThan(200_000);
+    expect(vault.unwrapRateLimit.available.toNumber()).to.be.lessThan(100_000);
+    console.log("✅ Buckets drained");
+
+    // A zero capacity switches the limit off
+    await setRateLimits(0, 0);
+    await wrap(mint.publicKey, testUser, 200_000);
+    await unwrap(mint.publicKey, testUser, 100_000);
+    console.log("✅ Disabled limits no longer apply");
+  });
+
+
+  it("Enforces daily per-user quotas and their overrides", async () => {
+    console.log("🎟️ Wrapping against user quotas...");
+
+    const mint = await createRestrictedMint();
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const userQuota = findUserQuota(mint.publicKey, testUser.publicKey);
+
+    await program.methods
+      .setVaultUserQuotas({ dailyWrapQuota: new anchor.BN(300_000), dailyUnwrapQuota: new anchor.BN(100_000) })
+      .accountsPartial({ tokenVault })
+      .rpc();
+
+    await wrap(mint.publicKey, testUser, 300_000);
+    await expectFailure(wrap(mint.publicKey, testUser, 1), "UserQuotaExceeded");
+
+    await unwrap(mint.publicKey, testUser, 100_000);
+    await expectFailure(unwrap(mint.publicKey, testUser, 1), "UserQuotaExceeded");
+
+    let quota = await program.account.userQuota.fetch(userQuota);
+    expect(quota.wrapped.toNumber()).to.equal(300_000);
+    expect(quota.unwrapped.toNumber()).to.equal(100_000);
+    console.log("✅ Vault defaults enforced");
+
+    // Raise the wrap quota for this user only
+    await program.methods
+      .setUserQuota(testUser.publicKey, new anchor.BN(500_000), null)
+      .accountsPartial({
+        tokenVault,
+        adminMultisig: null,
+        proposal: null,
+      })
+      .rpc();
+
+    await wrap(mint.publicKey, testUser, 200_000);
+    await expectFailure(wrap(mint.publicKey, testUser, 1), "UserQuotaExceeded");
+    await expectFailure(unwrap(mint.publicKey, testUser, 1), "UserQuotaExceeded");
+
+    quota = await program.account.userQuota.fetch(userQuota);
+    expect(quota.wrapQuota.toNumber()).to.equal(500_000);
+    expect(quota.unwrapQuota).to.be.null;
+    expect(quota.wrapped.toNumber()).to.equal(500_000);
+    console.log("✅ User override applied");
+  });
+
+  it("Charges limits, rate limits and quotas on the net amount locked", async () => {
+    console.log("🧮 Checking the limit basis with a 1% transfer fee and a 10% unwrap fee...");
+
+    const mint = await createRestrictedMint({
+      extensions: [ExtensionType.TransferFeeConfig],
+      extensionIxs: (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          wallet.publicKey,
+          wallet.publicKey,
+          100,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ],
+    });
+    await registerVault(mint.publicKey);
+    await fundUser(mint.publicKey, testUser.publicKey, 1_000_000);
+
+    const tokenVault = findTokenVault(mint.publicKey);
+    const userQuota = findUserQuota(mint.publicKey, testUser.publicKey);
+
+    await program.methods
+      .setVaultLimits({
+        maxTotalLocked: new anchor.BN(0),
+        minWrapAmount: new anchor.BN(99_000),
+        maxWrapAmount: new anchor.BN(99_000),
+        minUnwrapAmount: new anchor.BN(0),
+        maxUnwrapAmount: new anchor.BN(90_000),
+      })
+      .accountsPartial({ tokenVault })
+      .rpc();
+    await program.methods
+      .setVaultRateLimits(
+        { capacity: new anchor.BN(1_000_000), refillPerSecond: new anchor.BN(1) },
+        { capacity: new anchor.BN(1_000_000), refillPerSecond: new anchor.BN(1) }
+      )
+      .accountsPartial({ tokenVault })
+      .rpc();
+    await program.methods
+      .setVaultUserQuotas({ dailyWrapQuota: new anchor.BN(99_000), dailyUnwrapQuota: new anchor.BN(90_000) })
+      .accountsPartial({ tokenVault })
+      .rpc();
+
+    await program.methods.setFeeConfig(0, 0, 1_000).rpc();
+    try {
+      await program.methods.setVaultFees(null, 1_000).accountsPartial({ tokenVault }).rpc();
+
+      // 99,999 sent locks 98,999 after the fee; 100,500 sent locks 99,495
+      await expectFailure(wrap(mint.publicKey, testUser, 99_999), "AmountBelowMinimum");
+      await expectFailure(wrap(mint.publicKey, testUser, 100_500), "AmountAboveMaximum");
+
+      // 100,000 sent locks exactly 99,000: within the limits and the quota
+      await wrap(mint.publicKey, testUser, 100_000);
+
+      let vault = await program.account.tokenVault.fetch(tokenVault);
+      let quota = await program.account.userQuota.fetch(userQuota);
+      expect(vault.totalLocked.toNumber()).to.equal(99_000);
+      expect(vault.wrapRateLimit.available.toNumber()).to.equal(1_000_000 - 99_000);
+      expect(quota.wrapped.toNumber()).to.equal(99_000);
+      console.log("✅ Wrap charged on the 99,000 locked");
+
+      // Burning 99,000 redeems 89,100 after the unwrap fee, below the 90,000 maximum
+      await unwrap(mint.publicKey, testUser, 99_000);
+
+      vault = await program.account.tokenVault.fetch(tokenVault);
+      quota = await program.account.userQuota.fetch(userQuota);
+      expect(vault.totalLocked.toNumber()).to.equal(99_000 - 89_100);
+      expect(vault.unwrapRateLimit.available.toNumber()).to.equal(1_000_000 - 89_100);
+      expect(quota.unwrapped.toNumber()).to.equal(89_100);
+      console.log("✅ Unwrap charged on the 89,100 redeemed");
+    } finally {
+      await program.methods.setFeeConfig(0, 0, 0).rpc();
+    }
+  });
+});